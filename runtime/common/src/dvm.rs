@@ -0,0 +1,61 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Codec of the dvm address, the AccountId32 derived from an Ethereum address.
+//!
+//! `b"dvm:"` + `[0u8; 7]` + `the Ethereum address` + `checksum`
+//! Refer to https://github.com/darwinia-network/darwinia-common/blob/main/frame/support/src/evm.rs#L85
+
+// --- crates.io ---
+use ethereum_types::H160;
+
+/// The address prefix for dvm address
+const ADDR_PREFIX: &[u8] = b"dvm:";
+
+/// Encode the Ethereum address to the dvm address.
+pub fn encode(address: H160) -> [u8; 32] {
+	let mut raw_account = [0u8; 32];
+
+	raw_account[0..4].copy_from_slice(ADDR_PREFIX);
+	raw_account[11..31].copy_from_slice(&address[..]);
+	raw_account[31] = checksum_of(&raw_account);
+
+	raw_account
+}
+
+/// Decode the dvm address to the Ethereum address.
+/// Return `None` if the prefix, the zero padding or the checksum is invalid.
+pub fn decode(raw_account: &[u8; 32]) -> Option<H160> {
+	if is_dvm_address(raw_account) {
+		Some(H160::from_slice(&raw_account[11..31]))
+	} else {
+		None
+	}
+}
+
+/// Whether the account is a canonical dvm address, with the zero padding and a valid checksum.
+pub fn is_dvm_address(raw_account: &[u8; 32]) -> bool {
+	&raw_account[0..4] == ADDR_PREFIX
+		&& raw_account[4..11] == [0; 7]
+		&& raw_account[31] == checksum_of(raw_account)
+}
+
+/// The checksum of the first 31 bytes of the account.
+pub fn checksum_of(raw_account: &[u8; 32]) -> u8 {
+	raw_account[1..31].iter().fold(raw_account[0], |sum, &byte| sum ^ byte)
+}
@@ -29,21 +29,24 @@ use ethereum_types::{H160, H256, U256};
 use frame_support::pallet_prelude::PhantomData;
 use sp_runtime::DispatchError;
 use sp_std::{boxed::Box, vec, vec::Vec};
+// --- darwinia-network ---
+use crate::dvm;
 
 /// A trait for converting from Substrate account_id to Ethereum address.
 pub trait DeriveEthereumAddress<AccountId> {
-	fn derive_ethereum_address(account: AccountId) -> H160;
+	/// Return `None` if the account is not derived from an Ethereum address.
+	fn derive_ethereum_address(account: AccountId) -> Option<H160>;
 }
 
+/// Decode the Ethereum address from the dvm address.
 pub struct ConcatConverter<AccountId>(PhantomData<AccountId>);
 
 impl<AccountId> DeriveEthereumAddress<AccountId> for ConcatConverter<AccountId>
 where
 	AccountId: Into<[u8; 32]>,
 {
-	fn derive_ethereum_address(account: AccountId) -> H160 {
-		let bytes: [u8; 32] = account.into();
-		H160::from_slice(&bytes[11..31])
+	fn derive_ethereum_address(account: AccountId) -> Option<H160> {
+		dvm::decode(&account.into())
 	}
 }
 
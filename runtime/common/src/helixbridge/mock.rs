@@ -45,6 +45,10 @@ pub fn build_account(x: u8) -> (AccountId32, Vec<u8>) {
 	(AccountId32::decode(&mut &origin[..]).unwrap(), origin.to_vec())
 }
 
+pub fn build_dvm_account(x: u8) -> AccountId32 {
+	crate::dvm::encode(H160::repeat_byte(x)).into()
+}
+
 frame_support::parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
 }
@@ -505,6 +505,8 @@ pub mod pallet {
 		EvmEncodeFailed,
 		/// too many nonces received
 		TooManyNonces,
+		/// backing account is not derived from an ethereum address
+		InvalidBackingAccount,
 	}
 
 	#[pallet::genesis_config]
@@ -580,7 +582,8 @@ pub mod pallet {
 			input: Vec<u8>,
 		) -> Result<MessageNonce, DispatchErrorWithPostInfo> {
 			if let Some(backing) = <RemoteBackingAccount<T>>::get() {
-				let ethereum_account = T::IntoEthereumAccount::derive_ethereum_address(backing);
+				let ethereum_account = T::IntoEthereumAccount::derive_ethereum_address(backing)
+					.ok_or(<Error<T>>::InvalidBackingAccount)?;
 				let remote_call = evm::MessageEndpoint::encode_recv_message(input)
 					.map_err(|_| <Error<T>>::EvmEncodeFailed)?;
 				let ethereum_transaction = evm::new_ethereum_transaction(
@@ -18,6 +18,7 @@
 
 use crate::helixbridge::{mock::*, *};
 
+use sp_runtime::AccountId32;
 use sp_std::str::FromStr;
 
 // --- paritytech ---
@@ -64,7 +65,7 @@ fn encode_evm_abi() {
 fn issue_from_remote_backing_remote_sender_invalid() {
 	new_test_ext().execute_with(|| {
 		let (recipient, _recipient_vec) = build_account(10);
-		let (remote_backing_account, _) = build_account(3);
+		let remote_backing_account = build_dvm_account(3);
 		assert_ok!(S2sIssuing::set_remote_backing_account(
 			RawOrigin::Root.into(),
 			remote_backing_account
@@ -87,7 +88,7 @@ fn issue_from_remote_backing_remote_sender_invalid() {
 fn issue_from_remote_backing_success() {
 	new_test_ext().execute_with(|| {
 		let (recipient, _recipient_vec) = build_account(10);
-		let (remote_backing_account, _) = build_account(3);
+		let remote_backing_account = build_dvm_account(3);
 		let drived_remote_backing_account =
 			S2sIssuing::derived_backing_id(remote_backing_account.clone());
 		assert_ok!(S2sIssuing::set_remote_backing_account(
@@ -124,13 +125,56 @@ fn burn_and_remote_unlock_insufficient_balance() {
 }
 
 #[test]
-fn burn_and_remote_unlock_success() {
+fn burn_and_remote_unlock_invalid_backing_account() {
 	new_test_ext().execute_with(|| {
 		let (remote_backing_account, _) = build_account(3);
 		assert_ok!(S2sIssuing::set_remote_backing_account(
 			RawOrigin::Root.into(),
 			remote_backing_account
 		));
+		assert_err!(
+			S2sIssuing::burn_and_remote_unlock(
+				Origin::signed(build_account(1).0),
+				1,
+				1,
+				1000000,
+				10,
+				1,
+				H160::from_str("1234500000000000000000000000000000000000").unwrap(),
+			),
+			<Error<Test>>::InvalidBackingAccount
+		);
+	})
+}
+
+#[test]
+fn concat_converter_checks_dvm_address() {
+	let mut dvm_account: [u8; 32] = build_dvm_account(3).into();
+	assert_eq!(
+		evm::ConcatConverter::<AccountId32>::derive_ethereum_address(dvm_account.into()),
+		Some(H160::repeat_byte(3))
+	);
+	// invalid checksum
+	dvm_account[31] ^= 1;
+	assert_eq!(
+		evm::ConcatConverter::<AccountId32>::derive_ethereum_address(dvm_account.into()),
+		None
+	);
+	// invalid prefix
+	assert_eq!(
+		evm::ConcatConverter::<AccountId32>::derive_ethereum_address(build_account(3).0),
+		None
+	);
+}
+
+#[test]
+fn burn_and_remote_unlock_success() {
+	new_test_ext().execute_with(|| {
+		let remote_backing_account = build_dvm_account(3);
+		assert_ok!(S2sIssuing::set_remote_backing_account(
+			RawOrigin::Root.into(),
+			remote_backing_account
+		));
 		assert_ok!(S2sIssuing::burn_and_remote_unlock(
 			Origin::signed(build_account(1).0),
 			1,
@@ -148,7 +192,7 @@ fn burn_and_remote_unlock_success() {
 #[test]
 fn handle_issuing_failure_from_remote_success() {
 	new_test_ext().execute_with(|| {
-		let (remote_backing_account, _) = build_account(3);
+		let remote_backing_account = build_dvm_account(3);
 		assert_ok!(S2sIssuing::set_remote_backing_account(
 			RawOrigin::Root.into(),
 			remote_backing_account.clone(),
@@ -183,7 +227,7 @@ fn handle_issuing_failure_from_remote_success() {
 #[test]
 fn handle_issuing_failure_from_remote_failed() {
 	new_test_ext().execute_with(|| {
-		let (remote_backing_account, _) = build_account(3);
+		let remote_backing_account = build_dvm_account(3);
 		assert_ok!(S2sIssuing::set_remote_backing_account(
 			RawOrigin::Root.into(),
 			remote_backing_account.clone(),
@@ -319,7 +363,7 @@ fn handle_issuing_failure_from_remote_failed() {
 #[test]
 fn prun_message() {
 	new_test_ext().execute_with(|| {
-		let (remote_backing_account, _) = build_account(3);
+		let remote_backing_account = build_dvm_account(3);
 		let (recipient, _recipient_vec) = build_account(10);
 		assert_ok!(S2sIssuing::set_remote_backing_account(
 			RawOrigin::Root.into(),
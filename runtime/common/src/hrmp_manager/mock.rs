@@ -0,0 +1,142 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the HRMP manager module.
+
+mod hrmp_manager {
+	pub use super::super::*;
+}
+
+// --- core ---
+use core::cell::RefCell;
+// --- paritytech ---
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything},
+};
+use frame_system::{mocking::*, EnsureRoot};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+// --- darwinia-network ---
+use super::*;
+
+pub(crate) type AccountId = u64;
+pub(crate) type BlockNumber = u64;
+
+pub(crate) const PARA_ID: u32 = 2105;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Test {
+	type AccountData = ();
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = BlockNumber;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+thread_local! {
+	pub static SENT_XCM: RefCell<Vec<(MultiLocation, Xcm<()>)>> = RefCell::new(Vec::new());
+	pub static SEND_FAILURE: RefCell<bool> = RefCell::new(false);
+}
+pub(crate) fn sent_xcm() -> Vec<(MultiLocation, Xcm<()>)> {
+	SENT_XCM.with(|q| q.borrow().clone())
+}
+pub(crate) fn set_send_failure(failure: bool) {
+	SEND_FAILURE.with(|f| *f.borrow_mut() = failure);
+}
+pub(crate) struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	fn send_xcm(dest: impl Into<MultiLocation>, msg: Xcm<()>) -> SendResult {
+		if SEND_FAILURE.with(|f| *f.borrow()) {
+			return Err(SendError::Transport("Mocked failure"));
+		}
+
+		SENT_XCM.with(|q| q.borrow_mut().push((dest.into(), msg)));
+
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub SelfParaId: ParaId = ParaId::from(PARA_ID);
+	pub const HrmpPalletIndex: u8 = 60;
+	pub const RelayFee: u128 = 1_000;
+	pub const RelayCallWeight: Weight = 1_000_000;
+}
+
+impl Config for Test {
+	type Event = Event;
+	type HrmpPalletIndex = HrmpPalletIndex;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type RelayCallWeight = RelayCallWeight;
+	type RelayFee = RelayFee;
+	type SelfParaId = SelfParaId;
+	type XcmSender = TestSendXcm;
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = MockBlock<Test>,
+		NodeBlock = MockBlock<Test>,
+		UncheckedExtrinsic = MockUncheckedExtrinsic<Test>
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		HrmpManager: hrmp_manager::{Pallet, Call, Event<T>},
+	}
+);
+
+pub(crate) struct ExtBuilder;
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+
+		ext.execute_with(|| System::set_block_number(1));
+
+		ext
+	}
+}
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
@@ -0,0 +1,222 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Manage the HRMP channels of this chain by governance.
+//!
+//! The relay chain calls are encoded here and sent to the relay chain with a paid `Transact`, the
+//! execution is paid by the sovereign account of this chain on the relay chain. Dispatch the calls
+//! from root, e.g. by `Sudo` or `RemoteGovernance::enact_remote_call`.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- paritytech ---
+use frame_support::{pallet_prelude::*, weights::Weight};
+use polkadot_parachain::primitives::{HrmpChannelId, Id as ParaId};
+use sp_std::vec;
+use xcm::latest::prelude::*;
+
+/// The calls of the HRMP pallet on the relay chain.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub enum HrmpCall {
+	#[codec(index = 0)]
+	InitOpenChannel {
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	},
+	#[codec(index = 1)]
+	AcceptOpenChannel { sender: ParaId },
+	#[codec(index = 2)]
+	CloseChannel { channel_id: HrmpChannelId },
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::log;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which can manage the HRMP channels.
+		type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The para id of this chain.
+		type SelfParaId: Get<ParaId>;
+
+		/// The index of the HRMP pallet in the relay chain runtime.
+		#[pallet::constant]
+		type HrmpPalletIndex: Get<u8>;
+
+		/// The amount of the relay chain token withdrawn to pay the execution on the relay chain.
+		///
+		/// The surplus is refunded to the sovereign account of this chain.
+		#[pallet::constant]
+		type RelayFee: Get<u128>;
+
+		/// The weight of the HRMP calls on the relay chain.
+		#[pallet::constant]
+		type RelayCallWeight: Get<Weight>;
+
+		/// How to send the messages to the relay chain, e.g. `ParentAsUmp`.
+		type XcmSender: SendXcm;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Requested to open a channel to the recipient.
+		HrmpOpenRequested {
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		},
+		/// Accepted the channel request from the sender.
+		HrmpOpenAccepted { sender: ParaId },
+		/// Requested to close the channel.
+		HrmpCloseRequested { channel_id: HrmpChannelId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Can not open a channel to this chain itself.
+		SelfChannel,
+		/// The channel is neither from nor to this chain.
+		ForeignChannel,
+		/// Failed to send the message to the relay chain.
+		SendFailure,
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request to open a channel from this chain to the recipient.
+		///
+		/// The recipient needs to accept it before it is opened at the next session. The deposit
+		/// is reserved from the sovereign account of this chain on the relay chain.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2).saturating_add(50_000_000))]
+		pub fn request_hrmp_open(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(recipient != T::SelfParaId::get(), <Error<T>>::SelfChannel);
+
+			Self::send_to_relay(HrmpCall::InitOpenChannel {
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			})?;
+
+			Self::deposit_event(Event::HrmpOpenRequested {
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			});
+
+			Ok(().into())
+		}
+
+		/// Accept the channel request from the sender to this chain.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2).saturating_add(50_000_000))]
+		pub fn accept_hrmp_open(
+			origin: OriginFor<T>,
+			sender: ParaId,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(sender != T::SelfParaId::get(), <Error<T>>::SelfChannel);
+
+			Self::send_to_relay(HrmpCall::AcceptOpenChannel { sender })?;
+
+			Self::deposit_event(Event::HrmpOpenAccepted { sender });
+
+			Ok(().into())
+		}
+
+		/// Close the channel, either from or to this chain.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2).saturating_add(50_000_000))]
+		pub fn close_hrmp(
+			origin: OriginFor<T>,
+			sender: ParaId,
+			recipient: ParaId,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let self_para_id = T::SelfParaId::get();
+
+			ensure!(
+				sender == self_para_id || recipient == self_para_id,
+				<Error<T>>::ForeignChannel
+			);
+
+			let channel_id = HrmpChannelId { sender, recipient };
+
+			Self::send_to_relay(HrmpCall::CloseChannel { channel_id: channel_id.clone() })?;
+
+			Self::deposit_event(Event::HrmpCloseRequested { channel_id });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// Build the message which dispatches the HRMP call on the relay chain as this chain.
+		pub fn relay_xcm(call: HrmpCall) -> Xcm<()> {
+			let fee = MultiAsset::from((MultiLocation::here(), T::RelayFee::get()));
+
+			Xcm(vec![
+				WithdrawAsset(fee.clone().into()),
+				BuyExecution { fees: fee, weight_limit: Unlimited },
+				Transact {
+					origin_type: OriginKind::Native,
+					require_weight_at_most: T::RelayCallWeight::get(),
+					call: (T::HrmpPalletIndex::get(), call).encode().into(),
+				},
+				RefundSurplus,
+				DepositAsset {
+					assets: Wild(All),
+					max_assets: 1,
+					beneficiary: MultiLocation::new(0, X1(Parachain(T::SelfParaId::get().into()))),
+				},
+			])
+		}
+
+		fn send_to_relay(call: HrmpCall) -> Result<(), Error<T>> {
+			T::XcmSender::send_xcm(MultiLocation::parent(), Self::relay_xcm(call.clone())).map_err(
+				|error| {
+					log::error!("Failed to send {:?} to the relay chain with {:?}", call, error);
+
+					<Error<T>>::SendFailure
+				},
+			)
+		}
+	}
+}
+pub use pallet::*;
@@ -0,0 +1,149 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the HRMP manager module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+const MOONBEAM_PARA_ID: u32 = 2004;
+
+fn relay_xcm(call: Vec<u8>) -> Xcm<()> {
+	let fee = MultiAsset::from((MultiLocation::here(), RelayFee::get()));
+
+	Xcm(vec![
+		WithdrawAsset(fee.clone().into()),
+		BuyExecution { fees: fee, weight_limit: Unlimited },
+		Transact {
+			origin_type: OriginKind::Native,
+			require_weight_at_most: RelayCallWeight::get(),
+			call: call.into(),
+		},
+		RefundSurplus,
+		DepositAsset {
+			assets: Wild(All),
+			max_assets: 1,
+			beneficiary: MultiLocation::new(0, X1(Parachain(PARA_ID))),
+		},
+	])
+}
+
+#[test]
+fn request_hrmp_open_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let moonbeam = ParaId::from(MOONBEAM_PARA_ID);
+
+		assert_noop!(
+			HrmpManager::request_hrmp_open(Origin::signed(1), moonbeam, 8, 1024),
+			BadOrigin
+		);
+		assert_noop!(
+			HrmpManager::request_hrmp_open(Origin::root(), SelfParaId::get(), 8, 1024),
+			<Error<Test>>::SelfChannel
+		);
+
+		assert_ok!(HrmpManager::request_hrmp_open(Origin::root(), moonbeam, 8, 1024));
+		System::assert_last_event(
+			super::Event::<Test>::HrmpOpenRequested {
+				recipient: moonbeam,
+				proposed_max_capacity: 8,
+				proposed_max_message_size: 1024,
+			}
+			.into(),
+		);
+
+		// `Hrmp::hrmp_init_open_channel(2004, 8, 1024)`.
+		let call = [
+			vec![60, 0],
+			MOONBEAM_PARA_ID.to_le_bytes().to_vec(),
+			8_u32.to_le_bytes().to_vec(),
+			1024_u32.to_le_bytes().to_vec(),
+		]
+		.concat();
+
+		assert_eq!(sent_xcm(), vec![(MultiLocation::parent(), relay_xcm(call))]);
+	});
+}
+
+#[test]
+fn accept_hrmp_open_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let moonbeam = ParaId::from(MOONBEAM_PARA_ID);
+
+		assert_noop!(HrmpManager::accept_hrmp_open(Origin::signed(1), moonbeam), BadOrigin);
+
+		assert_ok!(HrmpManager::accept_hrmp_open(Origin::root(), moonbeam));
+		System::assert_last_event(
+			super::Event::<Test>::HrmpOpenAccepted { sender: moonbeam }.into(),
+		);
+
+		// `Hrmp::hrmp_accept_open_channel(2004)`.
+		let call = [vec![60, 1], MOONBEAM_PARA_ID.to_le_bytes().to_vec()].concat();
+
+		assert_eq!(sent_xcm(), vec![(MultiLocation::parent(), relay_xcm(call))]);
+	});
+}
+
+#[test]
+fn close_hrmp_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let moonbeam = ParaId::from(MOONBEAM_PARA_ID);
+
+		assert_noop!(
+			HrmpManager::close_hrmp(Origin::signed(1), SelfParaId::get(), moonbeam),
+			BadOrigin
+		);
+		// The relay chain would refuse it anyway, do not waste the fee.
+		assert_noop!(
+			HrmpManager::close_hrmp(Origin::root(), moonbeam, ParaId::from(2006)),
+			<Error<Test>>::ForeignChannel
+		);
+		assert!(sent_xcm().is_empty());
+
+		assert_ok!(HrmpManager::close_hrmp(Origin::root(), SelfParaId::get(), moonbeam));
+		System::assert_last_event(
+			super::Event::<Test>::HrmpCloseRequested {
+				channel_id: HrmpChannelId { sender: SelfParaId::get(), recipient: moonbeam },
+			}
+			.into(),
+		);
+
+		// `Hrmp::hrmp_close_channel(HrmpChannelId { sender: 2105, recipient: 2004 })`.
+		let call =
+			[vec![60, 2], PARA_ID.to_le_bytes().to_vec(), MOONBEAM_PARA_ID.to_le_bytes().to_vec()]
+				.concat();
+
+		assert_eq!(sent_xcm(), vec![(MultiLocation::parent(), relay_xcm(call))]);
+	});
+}
+
+#[test]
+fn send_failure_should_be_reported() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_send_failure(true);
+
+		assert_noop!(
+			HrmpManager::accept_hrmp_open(Origin::root(), ParaId::from(MOONBEAM_PARA_ID)),
+			<Error<Test>>::SendFailure
+		);
+		assert!(sent_xcm().is_empty());
+	});
+}
@@ -18,10 +18,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod dvm;
 pub mod helixbridge;
+pub mod hrmp_manager;
 pub mod message_router;
 pub mod remote_governance;
 pub mod xcm_config;
+pub mod xtokens;
 
 // --- core ---
 use core::marker::PhantomData;
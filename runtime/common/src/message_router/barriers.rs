@@ -20,7 +20,14 @@
 
 use frame_support::{ensure, log, traits::Contains, weights::Weight};
 use sp_std::{marker::PhantomData, result::Result};
-use xcm::latest::{Instruction::*, MultiLocation, WeightLimit::*, Xcm};
+use xcm::latest::{
+	Instruction::{self, *},
+	Junction::*,
+	Junctions::*,
+	MultiLocation,
+	WeightLimit::*,
+	Xcm,
+};
 use xcm_executor::traits::ShouldExecute;
 
 /// Allows execution from `origin` if it is contained in `T` (i.e. `T::Contains(origin)`) taking
@@ -30,6 +37,17 @@ use xcm_executor::traits::ShouldExecute;
 /// because they are the only ones that place assets in the Holding Register to pay for execution.
 /// Copy from https://github.com/paritytech/polkadot/blob/release-v0.9.26/xcm/xcm-builder/src/barriers.rs#L53
 /// Allow `DescendOrigin` as the first instruction to specify the payment account
+///
+/// The accepted message looks like:
+/// 1. `DescendOrigin` into an `AccountKey20` or an `AccountId32` account.
+/// 2. One of the instructions which place assets in the Holding Register.
+/// 3. An optional `WithdrawAsset`, paid from the descended account.
+/// 4. Any number of `ClearOrigin`.
+/// 5. `BuyExecution` of at least the weight of the message.
+///
+/// The `require_weight_at_most` of the following `Transact`s, including the ones nested in
+/// `SetAppendix` and `SetErrorHandler`, must fit within the bought weight. It does not rely on the
+/// weigher to count them.
 pub struct AllowDescendOriginPaidExecutionFrom<T>(PhantomData<T>);
 impl<T: Contains<MultiLocation>> ShouldExecute for AllowDescendOriginPaidExecutionFrom<T> {
 	fn should_execute<Call>(
@@ -43,36 +61,77 @@ impl<T: Contains<MultiLocation>> ShouldExecute for AllowDescendOriginPaidExecuti
 			"AllowDescendOriginPaidExecutionFrom origin: {:?}, message: {:?}, max_weight: {:?}, weight_credit: {:?}",
 			origin, message, max_weight, _weight_credit,
 		);
-		ensure!(T::contains(origin), ());
-		let mut iter = message.0.iter_mut();
-		let i = iter.next().ok_or(())?;
+
+		Self::check(origin, message, max_weight).map_err(|reason| {
+			log::trace!(
+				target: "xcm::barriers",
+				"AllowDescendOriginPaidExecutionFrom rejected the message from {:?}: {}",
+				origin, reason,
+			);
+		})
+	}
+}
+impl<T: Contains<MultiLocation>> AllowDescendOriginPaidExecutionFrom<T> {
+	fn check<Call>(
+		origin: &MultiLocation,
+		message: &mut Xcm<Call>,
+		max_weight: Weight,
+	) -> Result<(), &'static str> {
+		ensure!(T::contains(origin), "untrusted origin");
+		let mut iter = message.0.iter_mut().peekable();
+		let i = iter.next().ok_or("empty message")?;
 		// Modified: Only allows `DescendOrigin` as the first instruction
 		match i {
-			DescendOrigin(_) => (),
-			_ => return Err(()),
+			DescendOrigin(X1(AccountKey20 { .. } | AccountId32 { .. })) => (),
+			DescendOrigin(_) => return Err("not descended into an account"),
+			_ => return Err("the first instruction is not `DescendOrigin`"),
 		}
-		let i = iter.next().ok_or(())?;
+		let i = iter.next().ok_or("no assets to pay for the execution")?;
 		match i {
 			ReceiveTeleportedAsset(..)
 			| WithdrawAsset(..)
 			| ReserveAssetDeposited(..)
 			| ClaimAsset { .. } => (),
-			_ => return Err(()),
+			_ => return Err("no assets to pay for the execution"),
 		}
-		let mut i = iter.next().ok_or(())?;
+		// The descended account can top up the fees.
+		if matches!(iter.peek(), Some(WithdrawAsset(..))) {
+			iter.next();
+		}
+		let mut i = iter.next().ok_or("no execution bought")?;
 		while let ClearOrigin = i {
-			i = iter.next().ok_or(())?;
+			i = iter.next().ok_or("no execution bought")?;
 		}
-		match i {
+		let bought = match i {
 			BuyExecution { weight_limit: Limited(ref mut weight), .. } if *weight >= max_weight => {
 				*weight = max_weight;
-				Ok(())
+
+				*weight
 			},
 			BuyExecution { ref mut weight_limit, .. } if weight_limit == &Unlimited => {
 				*weight_limit = Limited(max_weight);
-				Ok(())
+
+				max_weight
 			},
-			_ => Err(()),
+			BuyExecution { .. } => return Err("bought less than the weight of the message"),
+			_ => return Err("no execution bought"),
+		};
+		let transact_weight =
+			iter.fold(0 as Weight, |weight, i| weight.saturating_add(Self::transact_weight(i)));
+
+		ensure!(transact_weight <= bought, "the `Transact` weight exceeds the bought weight");
+
+		Ok(())
+	}
+
+	/// The `require_weight_at_most` of the `Transact`s in the instruction, including the nested
+	/// ones.
+	fn transact_weight<Call>(instruction: &Instruction<Call>) -> Weight {
+		match instruction {
+			Transact { require_weight_at_most, .. } => *require_weight_at_most,
+			SetAppendix(xcm) | SetErrorHandler(xcm) =>
+				xcm.0.iter().fold(0, |weight, i| weight.saturating_add(Self::transact_weight(i))),
+			_ => 0,
 		}
 	}
 }
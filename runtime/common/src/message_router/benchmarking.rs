@@ -18,17 +18,68 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
+// --- crates.io ---
+use ethereum_types::{H160, U256};
 // --- paritytech ---
 use frame_benchmarking::{account, benchmarks};
-use frame_support::{assert_ok, traits::Get};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, EnsureOrigin, Get},
+};
 use frame_system::RawOrigin;
-use sp_std::{boxed::Box, vec};
+use sp_runtime::{traits::SaturatedConversion, Perbill};
+use sp_std::{boxed::Box, vec, vec::Vec};
 use xcm::{latest::prelude::*, VersionedXcm::V2};
+use xcm_executor::traits::Convert;
 // --- darwinia ---
-use crate::message_router::{Call, Config, Pallet};
+use crate::message_router::{
+	ethereum_xcm::{EthereumXcmConfig, MAX_ETHEREUM_XCM_INPUT_SIZE},
+	Call, CallerPolicy, Config, FeedBounds, ForwardRateLimit, Pallet, Target,
+};
 use dc_primitives::COIN;
 
+const UNITS_PER_SECOND: u128 = 14719736222326895902025_u128;
+
+/// Configure both of the targets with the feed bounds, and turn on the caller allowlist and the
+/// rate limit, so the forwards take the longest path. The caller is funded to pay the fees.
+fn setup_forward<T: Config>(caller: &T::AccountId) {
+	for target_location in [T::MoonbeamLocation::get(), T::AstarLocation::get()] {
+		assert_ok!(Pallet::<T>::set_target_xcm_exec_config(
+			RawOrigin::Root.into(),
+			target_location.clone(),
+			UNITS_PER_SECOND
+		));
+		assert_ok!(Pallet::<T>::set_target_xcm_exec_bounds(
+			RawOrigin::Root.into(),
+			target_location,
+			Some(FeedBounds {
+				min_units_per_second: 1_000_000_000_000_000_000_u128,
+				max_units_per_second: 100_000_000_000_000_000_000_000_u128,
+				max_change: Perbill::from_percent(10),
+				max_age: 7200_u32.into(),
+			})
+		));
+	}
+	assert_ok!(Pallet::<T>::set_forward_policy(RawOrigin::Root.into(), CallerPolicy::Allowlisted));
+	assert_ok!(Pallet::<T>::set_forward_rate_limit(
+		RawOrigin::Root.into(),
+		Some(ForwardRateLimit { period: 600_u32.into(), max_forwards: T::MaxForwardBatch::get() })
+	));
+	T::RingCurrency::make_free_balance_be(caller, (1_000_000_000 * COIN).saturated_into());
+}
+
+/// A `Transact` to forward.
+fn remote_xcm<T: Config>() -> Xcm<<T as frame_system::Config>::Call> {
+	Xcm(vec![Transact {
+		origin_type: OriginKind::SovereignAccount,
+		require_weight_at_most: 1_000_000_000,
+		call: vec![0u8; 32].into(),
+	}])
+}
+
 benchmarks! {
+	where_clause { where T::Origin: From<pallet_xcm::Origin> }
+
 	set_target_xcm_exec_config {
 		let target_location = T::MoonbeamLocation::get();
 		let local_asset_units_per_second: u128 = 14719736222326895902025_u128;
@@ -37,15 +88,158 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::target_xcm_exec_config(target_location), Some(local_asset_units_per_second));
 	}
 
-	forward_to_moonbeam {
+	set_target_xcm_exec_bounds {
+		let target_location = T::MoonbeamLocation::get();
+		let bounds = FeedBounds {
+			min_units_per_second: 1_000_000_000_000_000_000_u128,
+			max_units_per_second: 100_000_000_000_000_000_000_000_u128,
+			max_change: Perbill::from_percent(10),
+			max_age: 7200_u32.into(),
+		};
+	}:_(RawOrigin::Root, target_location.clone(), Some(bounds))
+	verify {
+		assert_eq!(Pallet::<T>::target_xcm_exec_bounds(target_location), Some(bounds));
+	}
+
+	feed_target_xcm_exec_config {
 		let target_location = T::MoonbeamLocation::get();
 		let local_asset_units_per_second: u128 = 14719736222326895902025_u128;
 		assert_ok!(Pallet::<T>::set_target_xcm_exec_config(RawOrigin::Root.into(), target_location.clone(), local_asset_units_per_second));
-		let xcm = Xcm(vec![
-			DescendOrigin(X1(AccountKey20 {
-					network: Any,
-					key: [0u8;20].into()
-				})),
-			]);
-	}:_(RawOrigin::Root, Box::new(V2(xcm)))
+		assert_ok!(Pallet::<T>::set_target_xcm_exec_bounds(
+			RawOrigin::Root.into(),
+			target_location.clone(),
+			Some(FeedBounds {
+				min_units_per_second: 1_000_000_000_000_000_000_u128,
+				max_units_per_second: 100_000_000_000_000_000_000_000_u128,
+				max_change: Perbill::from_percent(10),
+				max_age: 7200_u32.into(),
+			})
+		));
+		let new_local_asset_units_per_second = local_asset_units_per_second / 100 * 105;
+	}:_<T::Origin>(T::FeederOrigin::successful_origin(), target_location.clone(), new_local_asset_units_per_second)
+	verify {
+		assert_eq!(Pallet::<T>::target_xcm_exec_config(target_location), Some(new_local_asset_units_per_second));
+	}
+
+	set_target_ethereum_xcm_config {
+		let target_location = T::MoonbeamLocation::get();
+		let ethereum_xcm_config = EthereumXcmConfig {
+			pallet_index: 38,
+			call_index: 0,
+			weight_per_gas: 25_000,
+		};
+	}:_(RawOrigin::Root, target_location.clone(), Some(ethereum_xcm_config))
+	verify {
+		assert_eq!(Pallet::<T>::target_ethereum_xcm_config(target_location), Some(ethereum_xcm_config));
+	}
+
+	set_forward_policy {
+	}:_(RawOrigin::Root, CallerPolicy::Allowlisted)
+	verify {
+		assert_eq!(Pallet::<T>::forward_policy(), CallerPolicy::Allowlisted);
+	}
+
+	set_allowed_caller {
+		let caller: T::AccountId = account("caller", 0, 0);
+	}:_(RawOrigin::Root, caller.clone(), true)
+	verify {
+		assert!(Pallet::<T>::allowed_callers(caller).is_some());
+	}
+
+	set_allowed_bridged_caller {
+		let source_account: T::AccountId = account("source", 0, 0);
+	}:_(RawOrigin::Root, source_account.clone(), true)
+	verify {
+		assert_eq!(
+			Pallet::<T>::allowed_bridged_callers(Pallet::<T>::derived_bridged_account(source_account.clone())),
+			Some(source_account)
+		);
+	}
+
+	set_forward_rate_limit {
+		let rate_limit = ForwardRateLimit { period: 600_u32.into(), max_forwards: 10 };
+	}:_(RawOrigin::Root, Some(rate_limit))
+	verify {
+		assert_eq!(Pallet::<T>::forward_rate_limit(), Some(rate_limit));
+	}
+
+	forward {
+		let caller: T::AccountId = account("caller", 0, 0);
+		setup_forward::<T>(&caller);
+		assert_ok!(Pallet::<T>::set_allowed_caller(RawOrigin::Root.into(), caller.clone(), true));
+	}:_(RawOrigin::Signed(caller.clone()), Target::Moonbeam, Box::new(V2(remote_xcm::<T>())))
+	verify {
+		assert_eq!(Pallet::<T>::forward_count(caller).map(|(_, count)| count), Some(1));
+	}
+
+	forward_evm_call {
+		let caller: T::AccountId = account("caller", 0, 0);
+		setup_forward::<T>(&caller);
+		assert_ok!(Pallet::<T>::set_allowed_caller(RawOrigin::Root.into(), caller.clone(), true));
+		assert_ok!(Pallet::<T>::set_target_ethereum_xcm_config(
+			RawOrigin::Root.into(),
+			T::MoonbeamLocation::get(),
+			Some(EthereumXcmConfig { pallet_index: 38, call_index: 0, weight_per_gas: 25_000 })
+		));
+		let input = vec![0u8; MAX_ETHEREUM_XCM_INPUT_SIZE as usize];
+	}:_(RawOrigin::Signed(caller.clone()), Target::Moonbeam, H160::default(), input, 300_000, U256::zero())
+	verify {
+		assert_eq!(Pallet::<T>::forward_count(caller).map(|(_, count)| count), Some(1));
+	}
+
+	forward_from_bridge {
+		let source_account: T::AccountId = account("source", 0, 0);
+		let derived_account = Pallet::<T>::derived_bridged_account(source_account.clone());
+		setup_forward::<T>(&derived_account);
+		// Not in `AllowedCallers`, checked against `AllowedBridgedCallers` too.
+		assert_ok!(Pallet::<T>::set_allowed_bridged_caller(RawOrigin::Root.into(), source_account.clone(), true));
+	}:_(RawOrigin::Signed(derived_account.clone()), source_account, Target::Moonbeam, Box::new(V2(remote_xcm::<T>())))
+	verify {
+		assert_eq!(Pallet::<T>::forward_count(derived_account).map(|(_, count)| count), Some(1));
+	}
+
+	forward_to_bridge {
+		// The Moonbeam `AccountKey20` accounts are in the default descend origin allowlist.
+		let location = T::MoonbeamLocation::get()
+			.pushed_with_interior(AccountKey20 { network: Any, key: [0u8; 20] })
+			.unwrap();
+		let account = T::LocationToAccountId::convert_ref(&location).unwrap();
+		T::RingCurrency::make_free_balance_be(&account, (1_000_000 * COIN).saturated_into());
+		let call = vec![0u8; 1024];
+		let fee = (1_000 * COIN).saturated_into();
+	}:_<T::Origin>(pallet_xcm::Origin::Xcm(location).into(), 0, 0, call, fee)
+
+	forward_batch {
+		let n in 1 .. T::MaxForwardBatch::get();
+		let caller: T::AccountId = account("caller", 0, 0);
+		setup_forward::<T>(&caller);
+		assert_ok!(Pallet::<T>::set_allowed_caller(RawOrigin::Root.into(), caller.clone(), true));
+		let messages = (0..n)
+			.map(|i| {
+				let target = if i % 2 == 0 { Target::Moonbeam } else { Target::Astar };
+
+				(target, Box::new(V2(remote_xcm::<T>())))
+			})
+			.collect::<Vec<_>>();
+	}:_(RawOrigin::Signed(caller.clone()), messages)
+	verify {
+		assert_eq!(Pallet::<T>::forward_count(caller).map(|(_, count)| count), Some(n));
+	}
+
+	migrate_legacy_account {
+		let key = [1u8; 20];
+		let location = T::MoonbeamLocation::get()
+			.pushed_with_interior(AccountKey20 { network: NetworkId::Any, key })
+			.unwrap();
+		let account = T::LocationToAccountId::convert_ref(&location).unwrap();
+		let legacy_account = T::LocationToAccountId::convert(
+			MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key }))
+		)
+		.unwrap();
+		let amount = (1_000 * COIN).saturated_into();
+		T::RingCurrency::make_free_balance_be(&legacy_account, amount);
+	}:_(RawOrigin::Root, location, amount)
+	verify {
+		assert_eq!(T::RingCurrency::free_balance(&account), amount);
+	}
 }
@@ -0,0 +1,90 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Encoding of Moonbeam's `ethereum_xcm::transact` call.
+
+// --- crates.io ---
+use codec::{Decode, Encode, MaxEncodedLen};
+use ethereum::TransactionAction;
+use ethereum_types::{H160, H256, U256};
+use scale_info::TypeInfo;
+// --- paritytech ---
+use frame_support::{weights::Weight, RuntimeDebug};
+use sp_std::{vec, vec::Vec};
+
+/// Max input size of an ethereum xcm transaction, same as the one in Moonbeam.
+pub const MAX_ETHEREUM_XCM_INPUT_SIZE: u32 = 2u32.pow(16);
+
+/// The `EthereumXcm` pallet info of an EVM-capable target chain.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct EthereumXcmConfig {
+	/// The index of the `EthereumXcm` pallet in the target runtime.
+	pub pallet_index: u8,
+	/// The index of the `transact` call in the `EthereumXcm` pallet.
+	pub call_index: u8,
+	/// Used to convert the gas limit into the weight of the remote `Transact`.
+	pub weight_per_gas: Weight,
+}
+
+/// Copy from https://github.com/PureStake/moonbeam/blob/master/primitives/xcm/src/ethereum_xcm.rs
+/// Only the `V2` variant is used, `V1` is kept for the encoding index.
+#[derive(Clone, Encode, PartialEq, Eq, RuntimeDebug)]
+pub enum EthereumXcmTransaction {
+	#[codec(index = 0)]
+	V1,
+	#[codec(index = 1)]
+	V2(EthereumXcmTransactionV2),
+}
+
+/// The unsigned ethereum transaction, the signer is derived from the xcm origin by the target
+/// chain.
+#[derive(Clone, Encode, PartialEq, Eq, RuntimeDebug)]
+pub struct EthereumXcmTransactionV2 {
+	/// Gas limit to be consumed by EVM execution.
+	pub gas_limit: U256,
+	/// Either a Call (the callee, account or contract address) or Create (unsupported for v1).
+	pub action: TransactionAction,
+	/// Value to be transferred.
+	pub value: U256,
+	/// Input data for a contract call.
+	pub input: Vec<u8>,
+	/// Map of addresses to be pre-paid to warm storage.
+	pub access_list: Option<Vec<(H160, Vec<H256>)>>,
+}
+
+/// Build the encoded `ethereum_xcm::transact` call which calls `contract` on the target chain.
+pub fn encode_transact(
+	config: &EthereumXcmConfig,
+	contract: H160,
+	input: Vec<u8>,
+	gas_limit: u64,
+	value: U256,
+) -> Vec<u8> {
+	let mut encoded = vec![config.pallet_index, config.call_index];
+
+	EthereumXcmTransaction::V2(EthereumXcmTransactionV2 {
+		gas_limit: U256::from(gas_limit),
+		action: TransactionAction::Call(contract),
+		value,
+		input,
+		access_list: None,
+	})
+	.encode_to(&mut encoded);
+
+	encoded
+}
@@ -16,15 +16,21 @@
 // You should have received a copy of the GNU General Public License
 // along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
 
+// --- crates.io ---
+use ethereum_types::H160;
+// --- paritytech ---
+use frame_support::traits::Get;
 use sp_std::marker::PhantomData;
 use xcm::{latest::MultiLocation, prelude::*};
 use xcm_executor::traits::Convert;
+// --- darwinia-network ---
+use crate::dvm::{self, checksum_of};
 
-/// The address prefix for dvm address
-const ADDR_PREFIX: &[u8] = b"dvm:";
+/// The address prefix for the account derived from a sibling chain `AccountKey20`
+const SIBLING_ADDR_PREFIX: &[u8] = b"sibl";
 
-/// Derive AccountKey20 in MultiLocation to AccountId32
-/// Refer to https://github.com/darwinia-network/darwinia-common/blob/main/frame/support/src/evm.rs#L85
+/// Derive AccountKey20 in MultiLocation to AccountId32, the dvm address of the key.
+/// The dvm address is reversed to the local AccountKey20.
 pub struct AccountKey20Derive<AccountId>(PhantomData<AccountId>);
 impl<AccountId: From<[u8; 32]> + Into<[u8; 32]> + Clone> Convert<MultiLocation, AccountId>
 	for AccountKey20Derive<AccountId>
@@ -39,19 +45,72 @@ impl<AccountId: From<[u8; 32]> + Into<[u8; 32]> + Clone> Convert<MultiLocation,
 			} => key,
 			_ => return Err(location),
 		};
-		let mut raw_account = [0u8; 32];
 
-		raw_account[0..4].copy_from_slice(ADDR_PREFIX);
-		raw_account[11..31].copy_from_slice(&key[..]);
-		raw_account[31] = checksum_of(&raw_account);
-		Ok(raw_account.into())
+		Ok(dvm::encode(H160(key)).into())
 	}
 
 	fn reverse(who: AccountId) -> Result<MultiLocation, AccountId> {
-		Err(who)
+		match dvm::decode(&who.clone().into()) {
+			Some(address) => Ok(MultiLocation::new(
+				0,
+				X1(AccountKey20 { network: NetworkId::Any, key: address.0 }),
+			)),
+			None => Err(who),
+		}
 	}
 }
 
-fn checksum_of(account_id: &[u8; 32]) -> u8 {
-	account_id[1..31].iter().fold(account_id[0], |sum, &byte| sum ^ byte)
+/// Derive AccountKey20 in MultiLocation to AccountId32, and mix the para id into the account for
+/// the sibling chain AccountKey20, so the same H160 on different chains controls different
+/// accounts.
+///
+/// The local AccountKey20 is derived the same as `AccountKey20Derive`.
+/// If `Compat` is true, the sibling chain AccountKey20 is derived the same as `AccountKey20Derive`
+/// too, which keeps the existing funds accessible until they are moved to the new accounts.
+pub struct SiblingAccountKey20Derive<AccountId, Compat>(PhantomData<(AccountId, Compat)>);
+impl<AccountId: From<[u8; 32]> + Into<[u8; 32]> + Clone, Compat: Get<bool>>
+	Convert<MultiLocation, AccountId> for SiblingAccountKey20Derive<AccountId, Compat>
+{
+	fn convert(location: MultiLocation) -> Result<AccountId, MultiLocation> {
+		match location {
+			MultiLocation {
+				parents: 1,
+				interior: X2(Parachain(para_id), AccountKey20 { key, network: _ }),
+			} if !Compat::get() => {
+				let mut raw_account = [0u8; 32];
+
+				raw_account[0..4].copy_from_slice(SIBLING_ADDR_PREFIX);
+				raw_account[4..8].copy_from_slice(&para_id.to_le_bytes());
+				raw_account[11..31].copy_from_slice(&key[..]);
+				raw_account[31] = checksum_of(&raw_account);
+				Ok(raw_account.into())
+			},
+			location => AccountKey20Derive::<AccountId>::convert(location),
+		}
+	}
+
+	fn reverse(who: AccountId) -> Result<MultiLocation, AccountId> {
+		let raw_account: [u8; 32] = who.clone().into();
+
+		if &raw_account[0..4] != SIBLING_ADDR_PREFIX
+			|| raw_account[8..11] != [0; 3]
+			|| raw_account[31] != checksum_of(&raw_account)
+		{
+			return AccountKey20Derive::<AccountId>::reverse(who);
+		}
+
+		let mut para_id = [0u8; 4];
+		let mut key = [0u8; 20];
+
+		para_id.copy_from_slice(&raw_account[4..8]);
+		key.copy_from_slice(&raw_account[11..31]);
+
+		Ok(MultiLocation::new(
+			1,
+			X2(
+				Parachain(u32::from_le_bytes(para_id)),
+				AccountKey20 { network: NetworkId::Any, key },
+			),
+		))
+	}
 }
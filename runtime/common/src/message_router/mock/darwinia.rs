@@ -0,0 +1,325 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Darwinia-like parachain which runs the message router with the runtime's XCM config pieces.
+
+mod message_router {
+	pub use super::super::super::*;
+}
+
+// --- paritytech ---
+use bp_messages::{
+	source_chain::{MessagesBridge, SendMessageArtifacts},
+	LaneId,
+};
+use frame_support::{
+	construct_runtime,
+	dispatch::DispatchErrorWithPostInfo,
+	parameter_types,
+	traits::{ConstBool, ConstU32, EitherOfDiverse, Everything, Get, PalletInfoAccess},
+	weights::{IdentityFee, Weight},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use pallet_xcm::{EnsureXcm, XcmPassthrough};
+use polkadot_parachain::primitives::Sibling;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, CurrencyAdapter, EnsureXcmOrigin,
+	FixedWeightBounds, IsConcrete, LocationInverter, NativeAsset, ParentIsPreset,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, TakeWeightCredit, UsingComponents,
+};
+use xcm_executor::{traits::Convert as XcmConvert, XcmExecutor};
+// --- darwinia-network ---
+use super::{
+	mock_msg_queue, AccountId, Balance, ASTAR_PARA_ID, BOB, DARWINIA_PARA_ID, MOONBEAM_PARA_ID,
+};
+use crate::{
+	message_router::{
+		barriers::AllowDescendOriginPaidExecutionFrom,
+		location_conversion::SiblingAccountKey20Derive, CreateBridgePayload,
+	},
+	xcm_config::{DenyReserveTransferToRelayChain, DenyThenTry},
+};
+use bp_message_dispatch::CallOrigin;
+use bp_runtime::{messages::DispatchFeePayment, ChainId};
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+/// Converts a local signed origin into an XCM multilocation.
+pub type LocalOriginToLocation = SignedToAccountId32<Origin, AccountId, RelayNetwork>;
+
+pub type LocationToAccountId = (
+	ParentIsPreset<AccountId>,
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	SiblingAccountKey20Derive<AccountId, ConstBool<false>>,
+	AccountId32Aliases<RelayNetwork, AccountId>,
+);
+
+pub type LocalAssetTransactor =
+	CurrencyAdapter<Balances, IsConcrete<AnchoringSelfReserve>, LocationToAccountId, AccountId, ()>;
+
+pub type XcmOriginToTransactDispatchOrigin = (
+	SovereignSignedViaLocation<LocationToAccountId, Origin>,
+	SignedAccountId32AsNative<RelayNetwork, Origin>,
+	XcmPassthrough<Origin>,
+);
+
+pub type XcmRouter = super::ParachainXcmRouter<MsgQueue>;
+
+pub type Barrier = DenyThenTry<
+	DenyReserveTransferToRelayChain,
+	(
+		TakeWeightCredit,
+		AllowTopLevelPaidExecutionFrom<Everything>,
+		AllowDescendOriginPaidExecutionFrom<AllowDescendOrigin>,
+	),
+>;
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Kusama;
+	pub const MaxInstructions: u32 = 100;
+	pub AnchoringSelfReserve: MultiLocation = MultiLocation::new(
+		0,
+		X1(PalletInstance(<Balances as PalletInfoAccess>::index() as u8))
+	);
+	pub UnitWeightCost: Weight = 1_000_000_000;
+	pub Ancestry: MultiLocation = Parachain(MsgQueue::parachain_id().into()).into();
+}
+
+frame_support::match_types! {
+	pub type AllowDescendOrigin: impl Contains<MultiLocation> = {
+		MultiLocation { parents: 1, interior: X1(Parachain(MOONBEAM_PARA_ID)) } |
+		MultiLocation { parents: 1, interior: X1(Parachain(ASTAR_PARA_ID)) }
+	};
+}
+frame_support::match_types! {
+	pub type AllowDescendOriginAccounts: impl Contains<MultiLocation> = {
+		MultiLocation {
+			parents: 1,
+			interior: X2(Parachain(MOONBEAM_PARA_ID), AccountKey20 { .. })
+		} |
+		MultiLocation {
+			parents: 1,
+			interior: X2(Parachain(ASTAR_PARA_ID), AccountKey20 { .. })
+		}
+	};
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type AssetClaims = PolkadotXcm;
+	type AssetTransactor = LocalAssetTransactor;
+	type AssetTrap = PolkadotXcm;
+	type Barrier = Barrier;
+	type Call = Call;
+	type IsReserve = NativeAsset;
+	type IsTeleporter = ();
+	type LocationInverter = LocationInverter<Ancestry>;
+	type OriginConverter = XcmOriginToTransactDispatchOrigin;
+	type ResponseHandler = PolkadotXcm;
+	type SubscriptionService = PolkadotXcm;
+	type Trader =
+		UsingComponents<IdentityFee<Balance>, AnchoringSelfReserve, AccountId, Balances, ()>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmSender = XcmRouter;
+}
+
+impl pallet_xcm::Config for Runtime {
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Call = Call;
+	type Event = Event;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type LocationInverter = LocationInverter<Ancestry>;
+	type Origin = Origin;
+	type SendXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmReserveTransferFilter = Everything;
+	type XcmRouter = XcmRouter;
+	type XcmTeleportFilter = Everything;
+
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+}
+
+impl mock_msg_queue::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+pub struct AccountIdConverter;
+impl Convert<H256, AccountId> for AccountIdConverter {
+	fn convert(hash: H256) -> AccountId {
+		hash.to_fixed_bytes().into()
+	}
+}
+
+pub struct MockMessagesBridge;
+impl MessagesBridge<Origin, AccountId, Balance, ()> for MockMessagesBridge {
+	type Error = DispatchErrorWithPostInfo;
+
+	fn send_message(
+		_submitter: Origin,
+		_lane_id: LaneId,
+		_payload: (),
+		_fee: Balance,
+	) -> Result<SendMessageArtifacts, Self::Error> {
+		Ok(SendMessageArtifacts { nonce: 0, weight: 0 })
+	}
+}
+
+impl<SourceChainAccountId, Signer, Signature>
+	CreateBridgePayload<SourceChainAccountId, Signer, Signature> for ()
+{
+	type Payload = ();
+
+	fn create(
+		_: CallOrigin<SourceChainAccountId, Signer, Signature>,
+		_: u32,
+		_: u64,
+		_: Vec<u8>,
+		_: DispatchFeePayment,
+	) -> Result<Self::Payload, &'static str> {
+		Ok(())
+	}
+}
+
+parameter_types! {
+	// Same as the sibling chains' `UnitWeightCost`.
+	pub SiblingUnitWeightCost: Weight = super::sibling::UnitWeightCost::get();
+	pub SelfLocationInSibl: MultiLocation = MultiLocation::new(
+		1,
+		X1(Parachain(DARWINIA_PARA_ID))
+	);
+	pub MoonbeamLocation: MultiLocation = MultiLocation::new(
+		1,
+		X1(Parachain(MOONBEAM_PARA_ID))
+	);
+	pub AstarLocation: MultiLocation = MultiLocation::new(
+		1,
+		X1(Parachain(ASTAR_PARA_ID))
+	);
+	pub const MaxForwardBatch: u32 = 4;
+	pub const BridgedChainId: ChainId = *b"pagl";
+	pub const BridgeMessageLaneId: LaneId = *b"pali";
+}
+frame_support::ord_parameter_types! {
+	pub const Feeder: AccountId = BOB;
+}
+impl message_router::Config for Runtime {
+	type AstarLocation = AstarLocation;
+	type AstarWeigher = FixedWeightBounds<SiblingUnitWeightCost, Call, MaxInstructions>;
+	type BridgeForwardOrigin = EnsureXcm<AllowDescendOriginAccounts>;
+	type BridgeMessageLaneId = BridgeMessageLaneId;
+	type BridgedAccountIdConverter = AccountIdConverter;
+	type BridgedChainId = BridgedChainId;
+	type ConfigModifierOrigin = EnsureRoot<AccountId>;
+	type Event = Event;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type FeederOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<Feeder, AccountId>>;
+	type LocalAssetId = AnchoringSelfReserve;
+	type LocalWeigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type LocationInverter = LocationInverter<Ancestry>;
+	type LocationToAccountId = LocationToAccountId;
+	type MaxForwardBatch = MaxForwardBatch;
+	type MessagesBridge = MockMessagesBridge;
+	type MoonbeamLocation = MoonbeamLocation;
+	type MoonbeamWeigher = FixedWeightBounds<SiblingUnitWeightCost, Call, MaxInstructions>;
+	type OutboundPayloadCreator = ();
+	type RingCurrency = Balances;
+	type SelfLocationInSibl = SelfLocationInSibl;
+	type WeightInfo = ();
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmFeederOrigin = EnsureXcm<AllowDescendOrigin>;
+	type XcmSender = XcmRouter;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>} = 0,
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 5,
+		MsgQueue: mock_msg_queue::{Pallet, Storage, Event<T>} = 12,
+		PolkadotXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin} = 13,
+		MessageRouter: message_router::{Pallet, Call, Storage, Event<T>} = 26,
+	}
+);
+
+/// The local account of an `AccountKey20` on the sibling chain.
+pub fn account_key20_derived(para_id: u32, key: [u8; 20]) -> AccountId {
+	LocationToAccountId::convert(MultiLocation::new(
+		1,
+		X2(Parachain(para_id), AccountKey20 { network: NetworkId::Any, key }),
+	))
+	.unwrap()
+}
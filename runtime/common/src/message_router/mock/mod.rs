@@ -0,0 +1,303 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the message router module.
+//!
+//! An `xcm-simulator` network with a relay chain, a Darwinia-like parachain which runs the
+//! message router, and two sibling parachains standing in for Moonbeam and Astar.
+
+pub mod darwinia;
+pub mod relay_chain;
+pub mod sibling;
+
+// --- paritytech ---
+use frame_support::traits::GenesisBuild;
+use polkadot_parachain::primitives::{Id as ParaId, Sibling};
+use sp_runtime::{traits::AccountIdConversion, AccountId32};
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+pub const ALICE: AccountId = AccountId32::new([1; 32]);
+pub const BOB: AccountId = AccountId32::new([2; 32]);
+pub const ALICE_KEY20: [u8; 20] = [1; 20];
+
+pub const UNIT: Balance = 1_000_000_000_000_000_000;
+pub const INITIAL_BALANCE: Balance = 1_000 * UNIT;
+
+pub const DARWINIA_PARA_ID: u32 = 2046;
+pub const MOONBEAM_PARA_ID: u32 = 2004;
+pub const ASTAR_PARA_ID: u32 = 2006;
+
+/// The units per second of the Darwinia token charged by the sibling chains.
+pub const DARWINIA_TOKEN_UNITS_PER_SECOND: u128 = UNIT;
+
+decl_test_parachain! {
+	pub struct Darwinia {
+		Runtime = darwinia::Runtime,
+		XcmpMessageHandler = darwinia::MsgQueue,
+		DmpMessageHandler = darwinia::MsgQueue,
+		new_ext = darwinia_ext(),
+	}
+}
+
+decl_test_parachain! {
+	pub struct Moonbeam {
+		Runtime = sibling::Runtime,
+		XcmpMessageHandler = sibling::MsgQueue,
+		DmpMessageHandler = sibling::MsgQueue,
+		new_ext = sibling_ext(MOONBEAM_PARA_ID),
+	}
+}
+
+decl_test_parachain! {
+	pub struct Astar {
+		Runtime = sibling::Runtime,
+		XcmpMessageHandler = sibling::MsgQueue,
+		DmpMessageHandler = sibling::MsgQueue,
+		new_ext = sibling_ext(ASTAR_PARA_ID),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay_chain::Runtime,
+		XcmConfig = relay_chain::XcmConfig,
+		new_ext = relay_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct MockNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(DARWINIA_PARA_ID, Darwinia),
+			(MOONBEAM_PARA_ID, Moonbeam),
+			(ASTAR_PARA_ID, Astar),
+		],
+	}
+}
+
+/// The sovereign account of a parachain on its siblings.
+pub fn sibling_account_id(para_id: u32) -> AccountId {
+	Sibling::from(para_id).into_account_truncating()
+}
+
+/// The sovereign account of a parachain on the relay chain.
+pub fn child_account_id(para_id: u32) -> AccountId {
+	ParaId::from(para_id).into_account_truncating()
+}
+
+pub fn darwinia_ext() -> sp_io::TestExternalities {
+	let mut storage =
+		frame_system::GenesisConfig::default().build_storage::<darwinia::Runtime>().unwrap();
+
+	pallet_balances::GenesisConfig::<darwinia::Runtime> {
+		balances: vec![
+			(ALICE, INITIAL_BALANCE),
+			(darwinia::account_key20_derived(MOONBEAM_PARA_ID, ALICE_KEY20), INITIAL_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+
+	ext.execute_with(|| {
+		darwinia::System::set_block_number(1);
+		darwinia::MsgQueue::set_para_id(DARWINIA_PARA_ID.into());
+	});
+
+	ext
+}
+
+pub fn sibling_ext(para_id: u32) -> sp_io::TestExternalities {
+	let mut storage =
+		frame_system::GenesisConfig::default().build_storage::<sibling::Runtime>().unwrap();
+
+	pallet_balances::GenesisConfig::<sibling::Runtime> { balances: vec![] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+
+	ext.execute_with(|| {
+		sibling::System::set_block_number(1);
+		sibling::MsgQueue::set_para_id(para_id.into());
+	});
+
+	ext
+}
+
+pub fn relay_ext() -> sp_io::TestExternalities {
+	let mut storage =
+		frame_system::GenesisConfig::default().build_storage::<relay_chain::Runtime>().unwrap();
+
+	pallet_balances::GenesisConfig::<relay_chain::Runtime> {
+		balances: vec![(child_account_id(DARWINIA_PARA_ID), INITIAL_BALANCE)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+
+	ext.execute_with(|| relay_chain::System::set_block_number(1));
+
+	ext
+}
+
+/// A message queue which executes the XCMP and DMP messages with the XCM executor right away.
+/// Copy from https://github.com/paritytech/polkadot/blob/release-v0.9.27/xcm/xcm-simulator/example/src/parachain.rs
+#[frame_support::pallet]
+pub mod mock_msg_queue {
+	// --- paritytech ---
+	use codec::{Decode, Encode};
+	use frame_support::{pallet_prelude::*, weights::Weight};
+	use polkadot_core_primitives::BlockNumber as RelayBlockNumber;
+	use polkadot_parachain::primitives::{
+		DmpMessageHandler, Id as ParaId, XcmpMessageFormat, XcmpMessageHandler,
+	};
+	use sp_runtime::traits::Hash;
+	use sp_std::prelude::*;
+	use xcm::{latest::prelude::*, VersionedXcm};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type XcmExecutor: ExecuteXcm<Self::Call>;
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn parachain_id)]
+	pub(super) type ParachainId<T: Config> = StorageValue<_, ParaId, ValueQuery>;
+
+	impl<T: Config> Get<ParaId> for Pallet<T> {
+		fn get() -> ParaId {
+			Self::parachain_id()
+		}
+	}
+
+	pub type MessageId = [u8; 32];
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Some XCM was executed OK.
+		Success(Option<T::Hash>),
+		/// Some XCM failed.
+		Fail(Option<T::Hash>, XcmError),
+		/// Bad XCM version used.
+		BadVersion(Option<T::Hash>),
+		/// Downward message is invalid XCM.
+		InvalidFormat(MessageId),
+		/// Downward message is unsupported version of XCM.
+		UnsupportedVersion(MessageId),
+		/// Downward message executed with the given outcome.
+		ExecutedDownward(MessageId, Outcome),
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn set_para_id(para_id: ParaId) {
+			ParachainId::<T>::put(para_id);
+		}
+
+		fn handle_xcmp_message(
+			sender: ParaId,
+			xcm: VersionedXcm<T::Call>,
+			max_weight: Weight,
+		) -> Result<Weight, XcmError> {
+			let hash = Encode::using_encoded(&xcm, T::Hashing::hash);
+			let (result, event) = match Xcm::<T::Call>::try_from(xcm) {
+				Ok(xcm) => {
+					let location = (1, Parachain(sender.into()));
+
+					match T::XcmExecutor::execute_xcm(location, xcm, max_weight) {
+						Outcome::Error(e) => (Err(e.clone()), Event::Fail(Some(hash), e)),
+						Outcome::Complete(w) => (Ok(w), Event::Success(Some(hash))),
+						// As far as the caller is concerned, this was dispatched without error,
+						// so we just report the weight used.
+						Outcome::Incomplete(w, e) => (Ok(w), Event::Fail(Some(hash), e)),
+					}
+				},
+				Err(()) => (Err(XcmError::UnhandledXcmVersion), Event::BadVersion(Some(hash))),
+			};
+
+			Self::deposit_event(event);
+
+			result
+		}
+	}
+
+	impl<T: Config> XcmpMessageHandler for Pallet<T> {
+		fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+			iter: I,
+			max_weight: Weight,
+		) -> Weight {
+			for (sender, _sent_at, data) in iter {
+				let mut data_ref = data;
+				let _ = XcmpMessageFormat::decode(&mut data_ref)
+					.expect("Simulator encodes with versioned xcm format; qed");
+				let mut remaining_fragments = &data_ref[..];
+
+				while !remaining_fragments.is_empty() {
+					if let Ok(xcm) = VersionedXcm::<T::Call>::decode(&mut remaining_fragments) {
+						let _ = Self::handle_xcmp_message(sender, xcm, max_weight);
+					} else {
+						debug_assert!(false, "Invalid incoming XCMP message data");
+					}
+				}
+			}
+
+			max_weight
+		}
+	}
+
+	impl<T: Config> DmpMessageHandler for Pallet<T> {
+		fn handle_dmp_messages(
+			iter: impl Iterator<Item = (RelayBlockNumber, Vec<u8>)>,
+			limit: Weight,
+		) -> Weight {
+			for (_sent_at, data) in iter {
+				let id = sp_io::hashing::blake2_256(&data[..]);
+				let maybe_msg =
+					VersionedXcm::<T::Call>::decode(&mut &data[..]).map(Xcm::<T::Call>::try_from);
+
+				match maybe_msg {
+					Err(_) => Self::deposit_event(Event::InvalidFormat(id)),
+					Ok(Err(())) => Self::deposit_event(Event::UnsupportedVersion(id)),
+					Ok(Ok(x)) => {
+						let outcome = T::XcmExecutor::execute_xcm(Parent, x, limit);
+
+						Self::deposit_event(Event::ExecutedDownward(id, outcome));
+					},
+				}
+			}
+
+			limit
+		}
+	}
+}
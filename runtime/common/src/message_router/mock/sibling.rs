@@ -0,0 +1,163 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! A sibling parachain standing in for Moonbeam and Astar.
+//!
+//! Its balances represent the Darwinia token reserved on Darwinia, and the execution is paid in
+//! the Darwinia token at a fixed rate.
+
+// --- paritytech ---
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything, Get, PalletInfoAccess},
+	weights::Weight,
+};
+use polkadot_parachain::primitives::Sibling;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	Account32Hash, AccountId32Aliases, AllowTopLevelPaidExecutionFrom, Case, CurrencyAdapter,
+	FixedRateOfFungible, FixedWeightBounds, IsConcrete, LocationInverter,
+	SiblingParachainConvertsVia, SovereignSignedViaLocation, TakeWeightCredit,
+};
+use xcm_executor::XcmExecutor;
+// --- darwinia-network ---
+use super::{
+	mock_msg_queue, AccountId, Balance, DARWINIA_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND,
+};
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type Event = Event;
+	type ExistentialDeposit = ExistentialDeposit;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+
+pub type LocationToAccountId = (
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	AccountId32Aliases<RelayNetwork, AccountId>,
+	// The accounts descended from the sibling chains, e.g. `(1, X2(Parachain, AccountId32))`.
+	Account32Hash<RelayNetwork, AccountId>,
+);
+
+pub type LocalAssetTransactor =
+	CurrencyAdapter<Balances, IsConcrete<DarwiniaToken>, LocationToAccountId, AccountId, ()>;
+
+pub type XcmRouter = super::ParachainXcmRouter<MsgQueue>;
+
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Kusama;
+	pub const MaxInstructions: u32 = 100;
+	// https://github.com/PureStake/moonbeam/blob/master/runtime/moonbase/src/xcm_config.rs#L214
+	pub UnitWeightCost: Weight = 200_000_000;
+	pub Ancestry: MultiLocation = Parachain(MsgQueue::parachain_id().into()).into();
+	pub DarwiniaLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(DARWINIA_PARA_ID)));
+	pub DarwiniaToken: MultiLocation = MultiLocation::new(
+		1,
+		X2(
+			Parachain(DARWINIA_PARA_ID),
+			PalletInstance(<super::darwinia::Balances as PalletInfoAccess>::index() as u8)
+		)
+	);
+	pub DarwiniaTokenPerSecond: (AssetId, u128) =
+		(Concrete(DarwiniaToken::get()), DARWINIA_TOKEN_UNITS_PER_SECOND);
+	pub DarwiniaTokenFromDarwinia: (MultiAssetFilter, MultiLocation) = (
+		Wild(AllOf { id: Concrete(DarwiniaToken::get()), fun: WildFungible }),
+		DarwiniaLocation::get()
+	);
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type AssetClaims = ();
+	type AssetTransactor = LocalAssetTransactor;
+	type AssetTrap = ();
+	type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
+	type Call = Call;
+	type IsReserve = Case<DarwiniaTokenFromDarwinia>;
+	type IsTeleporter = ();
+	type LocationInverter = LocationInverter<Ancestry>;
+	type OriginConverter = SovereignSignedViaLocation<LocationToAccountId, Origin>;
+	type ResponseHandler = ();
+	type SubscriptionService = ();
+	type Trader = FixedRateOfFungible<DarwiniaTokenPerSecond, ()>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmSender = XcmRouter;
+}
+
+impl mock_msg_queue::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		MsgQueue: mock_msg_queue::{Pallet, Storage, Event<T>},
+	}
+);
@@ -21,15 +21,50 @@
 pub mod barriers;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod ethereum_xcm;
 pub mod location_conversion;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 mod weights;
 
+// --- crates.io ---
+use ethereum_types::{H160, U256};
 // --- paritytech ---
-use frame_support::{pallet_prelude::*, traits::Get};
+use frame_support::{
+	dispatch::DispatchErrorWithPostInfo,
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement, Get},
+};
+use sp_core::H256;
+use sp_runtime::{traits::Convert, MultiSignature, MultiSigner, Perbill};
+use sp_std::vec::Vec;
 use xcm::prelude::*;
-use xcm_executor::traits::WeightBounds;
+use xcm_executor::traits::{Convert as XcmConvert, WeightBounds};
+// --- darwinia-network ---
+use bp_message_dispatch::CallOrigin;
+use bp_messages::{source_chain::MessagesBridge, LaneId, MessageNonce};
+use bp_runtime::{derive_account_id, messages::DispatchFeePayment, ChainId, SourceAccount};
+use ethereum_xcm::{EthereumXcmConfig, MAX_ETHEREUM_XCM_INPUT_SIZE};
 
 pub type AssetUnitsPerSecond = u128;
+pub type BalanceOf<T> =
+	<<T as Config>::RingCurrency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Creating the payload of an outbound bridge message which carries an encoded call.
+pub trait CreateBridgePayload<SourceChainAccountId, TargetChainAccountPublic, TargetChainSignature>
+{
+	type Payload: Encode;
+
+	fn create(
+		origin: CallOrigin<SourceChainAccountId, TargetChainAccountPublic, TargetChainSignature>,
+		spec_version: u32,
+		weight: u64,
+		call: Vec<u8>,
+		dispatch_fee_payment: DispatchFeePayment,
+	) -> Result<Self::Payload, &'static str>;
+}
 
 /// router target
 #[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Debug)]
@@ -38,18 +73,81 @@ pub enum Target {
 	Astar,
 }
 
+/// The governance-defined bounds for feeding the units per second of a target chain.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct FeedBounds<BlockNumber> {
+	/// The minimum units per second that can be fed.
+	pub min_units_per_second: AssetUnitsPerSecond,
+	/// The maximum units per second that can be fed.
+	pub max_units_per_second: AssetUnitsPerSecond,
+	/// The maximum change of the units per second in one update.
+	pub max_change: Perbill,
+	/// The units per second is considered stale after this number of blocks, `forward` is
+	/// blocked until it gets updated again.
+	pub max_age: BlockNumber,
+}
+impl<BlockNumber> FeedBounds<BlockNumber> {
+	/// Check if `new` is acceptable when the current units per second is `old`.
+	pub fn check(&self, old: Option<AssetUnitsPerSecond>, new: AssetUnitsPerSecond) -> bool {
+		if new < self.min_units_per_second || new > self.max_units_per_second {
+			return false;
+		}
+
+		match old {
+			Some(old) => {
+				let max_change = self.max_change * old;
+
+				new <= old.saturating_add(max_change) && new >= old.saturating_sub(max_change)
+			},
+			None => true,
+		}
+	}
+}
+
+/// Who is allowed to forward messages.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum CallerPolicy {
+	/// Any signed account.
+	Open,
+	/// The accounts in `AllowedCallers` or `AllowedBridgedCallers`.
+	Allowlisted,
+	/// The accounts in `AllowedBridgedCallers` only.
+	BridgedOnly,
+}
+impl Default for CallerPolicy {
+	fn default() -> Self {
+		Self::Open
+	}
+}
+
+/// Limit the number of forwards of each account in a period.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct ForwardRateLimit<BlockNumber> {
+	/// The length of a period in blocks.
+	pub period: BlockNumber,
+	/// The maximum number of forwards of an account in a period.
+	pub max_forwards: u32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use crate::message_router::weights::WeightInfo;
 	use frame_support::{log, weights::constants::WEIGHT_PER_SECOND};
-	use frame_system::pallet_prelude::*;
+	use frame_system::{pallet_prelude::*, RawOrigin};
+	use sp_runtime::traits::{Saturating, Zero};
 	use sp_std::{boxed::Box, vec};
 	use xcm_executor::traits::InvertLocation;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type ConfigModifierOrigin: EnsureOrigin<Self::Origin>;
+		/// The designated origin which is allowed to feed the units per second of any target
+		/// chain.
+		type FeederOrigin: EnsureOrigin<Self::Origin>;
+		/// The origin of the XCM sent by a target chain, which is allowed to feed the units per
+		/// second of itself.
+		type XcmFeederOrigin: EnsureOrigin<Self::Origin, Success = MultiLocation>;
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// Required origin for executing XCM messages.
 		type ExecuteXcmOrigin: EnsureOrigin<
@@ -69,6 +167,41 @@ pub mod pallet {
 		type AstarWeigher: WeightBounds<Self::Call>;
 		/// This chain location relative to sibling chain
 		type SelfLocationInSibl: Get<MultiLocation>;
+		/// The maximum number of messages in a `forward_batch`.
+		#[pallet::constant]
+		type MaxForwardBatch: Get<u32>;
+		/// The bridged chain id.
+		type BridgedChainId: Get<ChainId>;
+		/// The bridge account id converter.
+		/// `remote account` + `remote chain id` derive the new account.
+		type BridgedAccountIdConverter: Convert<H256, Self::AccountId>;
+		/// The origin of the XCM from a sibling chain account, which is allowed to send
+		/// messages to the bridged chain.
+		type BridgeForwardOrigin: EnsureOrigin<Self::Origin, Success = MultiLocation>;
+		/// Convert the sibling chain account location to the local account which pays the
+		/// bridge fee.
+		type LocationToAccountId: XcmConvert<MultiLocation, Self::AccountId>;
+		/// The *RING* currency, used to pay the bridge fee.
+		type RingCurrency: Currency<Self::AccountId>;
+		/// Outbound payload creator used for s2s message.
+		type OutboundPayloadCreator: CreateBridgePayload<
+			Self::AccountId,
+			MultiSigner,
+			MultiSignature,
+		>;
+		/// The lane id of the s2s bridge.
+		type BridgeMessageLaneId: Get<LaneId>;
+		type MessagesBridge: MessagesBridge<
+			Self::Origin,
+			Self::AccountId,
+			BalanceOf<Self>,
+			<Self::OutboundPayloadCreator as CreateBridgePayload<
+				Self::AccountId,
+				MultiSigner,
+				MultiSignature,
+			>>::Payload,
+			Error = DispatchErrorWithPostInfo,
+		>;
 		type WeightInfo: WeightInfo;
 		type XcmExecutor: ExecuteXcm<Self::Call>;
 		type XcmSender: SendXcm;
@@ -82,6 +215,53 @@ pub mod pallet {
 			target_location: MultiLocation,
 			local_asset_units_per_second: AssetUnitsPerSecond,
 		},
+		/// The units per second of the target chain just fed.
+		TargetXcmExecConfigFed {
+			target_location: MultiLocation,
+			local_asset_units_per_second: AssetUnitsPerSecond,
+		},
+		/// Changed the bounds for feeding the units per second of the target chain.
+		TargetXcmExecBoundsChanged {
+			target_location: MultiLocation,
+			bounds: Option<FeedBounds<T::BlockNumber>>,
+		},
+		/// Changed the `EthereumXcm` pallet info of an EVM-capable target chain
+		TargetEthereumXcmConfigChanged {
+			target_location: MultiLocation,
+			ethereum_xcm_config: Option<EthereumXcmConfig>,
+		},
+		/// Changed the policy of who is allowed to forward messages.
+		ForwardPolicyChanged { policy: CallerPolicy },
+		/// Added or removed a local account from the callers allowlist.
+		AllowedCallerChanged { account: T::AccountId, allowed: bool },
+		/// Added or removed a bridged account from the callers allowlist.
+		AllowedBridgedCallerChanged {
+			source_account: T::AccountId,
+			derived_account: T::AccountId,
+			allowed: bool,
+		},
+		/// Changed the rate limit of forwards per account.
+		ForwardRateLimitChanged { rate_limit: Option<ForwardRateLimit<T::BlockNumber>> },
+		/// Routed an XCM carried by an inbound bridge message.
+		ForwardFromBridge {
+			source_account: T::AccountId,
+			derived_account: T::AccountId,
+			target: Target,
+		},
+		/// Sent a message to the bridged chain for a sibling chain account.
+		ForwardToBridge {
+			location: MultiLocation,
+			account: T::AccountId,
+			lane_id: LaneId,
+			nonce: MessageNonce,
+		},
+		/// Moved the funds of the legacy account of a sibling chain AccountKey20 to its account.
+		LegacyAccountMigrated {
+			location: MultiLocation,
+			legacy_account: T::AccountId,
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 		/// Deposited when successfully routed.
 		/// (send origin, route target, remote xcm, required weight, tokens used)
 		ForwardTo(MultiLocation, Target, Xcm<()>, Weight, u128),
@@ -102,6 +282,35 @@ pub mod pallet {
 		XcmSendFailed,
 		/// Failed to convert account id to [u8; 32].
 		AccountIdConversionFailed,
+		/// The target chain is not registered as EVM-capable.
+		TargetNotEvmCapable,
+		/// The input of the EVM call is too large.
+		EvmInputTooLarge,
+		/// Not config the bounds for feeding the target's units per second.
+		TargetXcmExecBoundsNotConfig,
+		/// The fed units per second is out of the bounds.
+		UnitsPerSecondOutOfBounds,
+		/// The XCM feeder can only feed the units per second of itself.
+		FeederLocationMismatch,
+		/// The units per second of the target chain is too old to be used.
+		StaleUnitsPerSecond,
+		/// The caller is not allowed to forward messages.
+		CallerNotAllowed,
+		/// The caller has reached the forwards limitation of the current period.
+		ForwardRateLimited,
+		/// The period of the rate limit must not be zero.
+		InvalidRateLimit,
+		/// Origin MUST be the account derived from the source account on the bridged chain.
+		RequireBridgedOrigin,
+		/// The batch contains no message.
+		EmptyBatch,
+		/// The batch contains too many messages.
+		TooManyMessages,
+		/// The location is not a sibling chain AccountKey20, or its account is still the same as
+		/// the legacy account.
+		NoLegacyAccount,
+		/// The minimum units per second of the feed bounds must not exceed the maximum.
+		InvalidFeedBounds,
 	}
 
 	/// Stores the units per second executed by the target chain for local asset(e.g. CRAB).
@@ -114,6 +323,55 @@ pub mod pallet {
 	pub type TargetXcmExecConfig<T: Config> =
 		StorageMap<_, Blake2_128Concat, MultiLocation, AssetUnitsPerSecond>;
 
+	/// The block number when the units per second of the target chain was updated.
+	#[pallet::storage]
+	#[pallet::getter(fn target_xcm_exec_config_updated_at)]
+	pub type TargetXcmExecConfigUpdatedAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, T::BlockNumber>;
+
+	/// The bounds for feeding the units per second of the target chain.
+	/// Feeding is disabled and the stale guard is off if the target has no bounds.
+	#[pallet::storage]
+	#[pallet::getter(fn target_xcm_exec_bounds)]
+	pub type TargetXcmExecBounds<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, FeedBounds<T::BlockNumber>>;
+
+	/// The policy of who is allowed to forward messages.
+	#[pallet::storage]
+	#[pallet::getter(fn forward_policy)]
+	pub type ForwardPolicy<T> = StorageValue<_, CallerPolicy, ValueQuery>;
+
+	/// The local accounts allowed to forward messages under the `Allowlisted` policy.
+	#[pallet::storage]
+	#[pallet::getter(fn allowed_callers)]
+	pub type AllowedCallers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The bridged accounts allowed to forward messages.
+	/// `derived account` => `source account on the bridged chain`
+	#[pallet::storage]
+	#[pallet::getter(fn allowed_bridged_callers)]
+	pub type AllowedBridgedCallers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// The rate limit of forwards per account. No limitation if it's `None`.
+	#[pallet::storage]
+	#[pallet::getter(fn forward_rate_limit)]
+	pub type RateLimit<T: Config> = StorageValue<_, ForwardRateLimit<T::BlockNumber>>;
+
+	/// The forwards count of an account in the current period.
+	/// `account` => `(period start, count)`
+	#[pallet::storage]
+	#[pallet::getter(fn forward_count)]
+	pub type ForwardCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::BlockNumber, u32)>;
+
+	/// Stores the `EthereumXcm` pallet info of the EVM-capable target chains.
+	/// Only the registered target chains can be called by `forward_evm_call`.
+	#[pallet::storage]
+	#[pallet::getter(fn target_ethereum_xcm_config)]
+	pub type TargetEthereumXcmConfig<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, EthereumXcmConfig>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
 	pub struct Pallet<T>(PhantomData<T>);
@@ -132,6 +390,10 @@ pub mod pallet {
 			T::ConfigModifierOrigin::ensure_origin(origin)?;
 
 			TargetXcmExecConfig::<T>::insert(&target_location, &local_asset_units_per_second);
+			TargetXcmExecConfigUpdatedAt::<T>::insert(
+				&target_location,
+				<frame_system::Pallet<T>>::block_number(),
+			);
 
 			Self::deposit_event(Event::TargetXcmExecConfigChanged {
 				target_location,
@@ -141,7 +403,77 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Deliver received LCMP messages to other parachains.
+		/// Update the bounds for feeding the units per second of the target chain.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_target_xcm_exec_bounds()
+		)]
+		pub fn set_target_xcm_exec_bounds(
+			origin: OriginFor<T>,
+			target_location: MultiLocation,
+			bounds: Option<FeedBounds<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			if let Some(FeedBounds { min_units_per_second, max_units_per_second, .. }) = bounds {
+				ensure!(
+					min_units_per_second <= max_units_per_second,
+					Error::<T>::InvalidFeedBounds
+				);
+			}
+
+			TargetXcmExecBounds::<T>::set(&target_location, bounds);
+
+			Self::deposit_event(Event::TargetXcmExecBoundsChanged { target_location, bounds });
+
+			Ok(().into())
+		}
+
+		/// Feed the units per second of local asset used in target chain.
+		///
+		/// The feeder origin can feed any target, while a target chain can only feed itself
+		/// through XCM. The new value must be in the target's bounds.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::feed_target_xcm_exec_config()
+		)]
+		pub fn feed_target_xcm_exec_config(
+			origin: OriginFor<T>,
+			target_location: MultiLocation,
+			local_asset_units_per_second: AssetUnitsPerSecond,
+		) -> DispatchResultWithPostInfo {
+			match T::XcmFeederOrigin::try_origin(origin) {
+				Ok(feeder_location) =>
+					ensure!(feeder_location == target_location, Error::<T>::FeederLocationMismatch),
+				Err(origin) => {
+					T::FeederOrigin::ensure_origin(origin)?;
+				},
+			}
+
+			let bounds = TargetXcmExecBounds::<T>::get(&target_location)
+				.ok_or(Error::<T>::TargetXcmExecBoundsNotConfig)?;
+
+			ensure!(
+				bounds.check(
+					TargetXcmExecConfig::<T>::get(&target_location),
+					local_asset_units_per_second
+				),
+				Error::<T>::UnitsPerSecondOutOfBounds
+			);
+
+			TargetXcmExecConfig::<T>::insert(&target_location, &local_asset_units_per_second);
+			TargetXcmExecConfigUpdatedAt::<T>::insert(
+				&target_location,
+				<frame_system::Pallet<T>>::block_number(),
+			);
+
+			Self::deposit_event(Event::TargetXcmExecConfigFed {
+				target_location,
+				local_asset_units_per_second,
+			});
+
+			Ok(().into())
+		}
+
+		/// Deliver the sender's xcm to other parachains.
 		/// 1. Calculate the fee for xcm remote execution
 		/// 2. Transfer xcm fee to target sovereign account
 		/// 3. Assemble xcm that needs to be executed remotely
@@ -153,66 +485,389 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			target: Target,
 			message: Box<VersionedXcm<<T as frame_system::Config>::Call>>,
+		) -> DispatchResultWithPostInfo {
+			let remote_xcm: Xcm<<T as frame_system::Config>::Call> =
+				(*message).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			Self::do_forward(origin, target, remote_xcm)
+		}
+
+		/// Deliver the XCM carried by an inbound bridge message to other parachains.
+		///
+		/// The call must be dispatched by `pallet_bridge_dispatch` with the account derived from
+		/// `source_account` on the bridged chain, and the XCM is executed under that derived
+		/// account, the same as `forward`.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::forward_from_bridge()
+		)]
+		pub fn forward_from_bridge(
+			origin: OriginFor<T>,
+			source_account: T::AccountId,
+			target: Target,
+			message: Box<VersionedXcm<<T as frame_system::Config>::Call>>,
+		) -> DispatchResultWithPostInfo {
+			let derived_account = ensure_signed(origin.clone())?;
+
+			ensure!(
+				derived_account == Self::derived_bridged_account(source_account.clone()),
+				Error::<T>::RequireBridgedOrigin
+			);
+
+			let remote_xcm: Xcm<<T as frame_system::Config>::Call> =
+				(*message).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			Self::do_forward(origin, target.clone(), remote_xcm)?;
+
+			Self::deposit_event(Event::ForwardFromBridge {
+				source_account,
+				derived_account,
+				target,
+			});
+
+			Ok(().into())
+		}
+
+		/// Deliver several xcm messages of the sender to other parachains.
+		///
+		/// The fees of all the messages are paid in one local xcm execution, and nothing is sent
+		/// if any of the messages fails.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::forward_batch(messages.len() as u32)
+		)]
+		pub fn forward_batch(
+			origin: OriginFor<T>,
+			messages: Vec<(Target, Box<VersionedXcm<<T as frame_system::Config>::Call>>)>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(!messages.is_empty(), Error::<T>::EmptyBatch);
+			ensure!(
+				messages.len() <= T::MaxForwardBatch::get() as usize,
+				Error::<T>::TooManyMessages
+			);
+
+			let messages = messages
+				.into_iter()
+				.map(|(target, message)| {
+					(*message).try_into().map(|remote_xcm| (target, remote_xcm))
+				})
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|()| Error::<T>::BadVersion)?;
+
+			Self::do_forward_batch(origin, messages)
+		}
+
+		/// Send the call to the bridged chain for a sibling chain account.
+		///
+		/// The sibling chain must `Transact` this call with `OriginKind::Xcm` after descending to
+		/// the sender's account. The bridge fee is paid from the local account of the sender,
+		/// which is also the source account of the message on the bridged chain.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::forward_to_bridge()
+		)]
+		pub fn forward_to_bridge(
+			origin: OriginFor<T>,
+			spec_version: u32,
+			weight: u64,
+			call: Vec<u8>,
+			#[pallet::compact] fee: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let location = T::BridgeForwardOrigin::ensure_origin(origin)?;
+			let account = T::LocationToAccountId::convert_ref(&location)
+				.map_err(|()| Error::<T>::AccountIdConversionFailed)?;
+			let payload = T::OutboundPayloadCreator::create(
+				CallOrigin::SourceAccount(account.clone()),
+				spec_version,
+				weight,
+				call,
+				DispatchFeePayment::AtSourceChain,
+			)?;
+			let lane_id = T::BridgeMessageLaneId::get();
+			let artifacts = T::MessagesBridge::send_message(
+				RawOrigin::Signed(account.clone()).into(),
+				lane_id,
+				payload,
+				fee,
+			)?;
+
+			Self::deposit_event(Event::ForwardToBridge {
+				location,
+				account,
+				lane_id,
+				nonce: artifacts.nonce,
+			});
+
+			Ok(().into())
+		}
+
+		/// Update the `EthereumXcm` pallet info of an EVM-capable target chain.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_target_ethereum_xcm_config()
+		)]
+		pub fn set_target_ethereum_xcm_config(
+			origin: OriginFor<T>,
+			target_location: MultiLocation,
+			ethereum_xcm_config: Option<EthereumXcmConfig>,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			TargetEthereumXcmConfig::<T>::set(&target_location, ethereum_xcm_config);
+
+			Self::deposit_event(Event::TargetEthereumXcmConfigChanged {
+				target_location,
+				ethereum_xcm_config,
+			});
+
+			Ok(().into())
+		}
+
+		/// Call an EVM contract of the target chain through its `ethereum_xcm::transact`.
+		/// The `Transact` is dispatched with the origin descended from the sender, the same as
+		/// `forward`.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::forward_evm_call()
+		)]
+		pub fn forward_evm_call(
+			origin: OriginFor<T>,
+			target: Target,
+			contract: H160,
+			input: Vec<u8>,
+			gas_limit: u64,
+			value: U256,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				input.len() <= MAX_ETHEREUM_XCM_INPUT_SIZE as usize,
+				Error::<T>::EvmInputTooLarge
+			);
+
+			let ethereum_xcm_config =
+				TargetEthereumXcmConfig::<T>::get(Self::target_location(&target))
+					.ok_or(Error::<T>::TargetNotEvmCapable)?;
+			let remote_xcm = Xcm(vec![Transact {
+				origin_type: OriginKind::SovereignAccount,
+				require_weight_at_most: gas_limit
+					.saturating_mul(ethereum_xcm_config.weight_per_gas),
+				call: ethereum_xcm::encode_transact(
+					&ethereum_xcm_config,
+					contract,
+					input,
+					gas_limit,
+					value,
+				)
+				.into(),
+			}]);
+
+			Self::do_forward(origin, target, remote_xcm)
+		}
+
+		/// Update the policy of who is allowed to forward messages.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_forward_policy()
+		)]
+		pub fn set_forward_policy(
+			origin: OriginFor<T>,
+			policy: CallerPolicy,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			ForwardPolicy::<T>::put(policy);
+
+			Self::deposit_event(Event::ForwardPolicyChanged { policy });
+
+			Ok(().into())
+		}
+
+		/// Add or remove a local account from the callers allowlist.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_allowed_caller()
+		)]
+		pub fn set_allowed_caller(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			allowed: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			if allowed {
+				AllowedCallers::<T>::insert(&account, ());
+			} else {
+				AllowedCallers::<T>::remove(&account);
+			}
+
+			Self::deposit_event(Event::AllowedCallerChanged { account, allowed });
+
+			Ok(().into())
+		}
+
+		/// Add or remove a bridged account from the callers allowlist.
+		/// The `source_account` is the sender on the bridged chain, its derived account on this
+		/// chain is the one which dispatches the call.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_allowed_bridged_caller()
+		)]
+		pub fn set_allowed_bridged_caller(
+			origin: OriginFor<T>,
+			source_account: T::AccountId,
+			allowed: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			let derived_account = Self::derived_bridged_account(source_account.clone());
+
+			if allowed {
+				AllowedBridgedCallers::<T>::insert(&derived_account, &source_account);
+			} else {
+				AllowedBridgedCallers::<T>::remove(&derived_account);
+			}
+
+			Self::deposit_event(Event::AllowedBridgedCallerChanged {
+				source_account,
+				derived_account,
+				allowed,
+			});
+
+			Ok(().into())
+		}
+
+		/// Update the rate limit of forwards per account.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::set_forward_rate_limit()
+		)]
+		pub fn set_forward_rate_limit(
+			origin: OriginFor<T>,
+			rate_limit: Option<ForwardRateLimit<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			if let Some(ForwardRateLimit { period, .. }) = rate_limit {
+				ensure!(!period.is_zero(), Error::<T>::InvalidRateLimit);
+			}
+
+			RateLimit::<T>::set(rate_limit);
+
+			Self::deposit_event(Event::ForwardRateLimitChanged { rate_limit });
+
+			Ok(().into())
+		}
+
+		/// Move the *RING* of the legacy account of a sibling chain AccountKey20 to its current
+		/// account.
+		///
+		/// The sibling chain AccountKey20 used to be derived without the para id, to the same
+		/// account as the local AccountKey20. The same AccountKey20 on different sibling chains
+		/// shares that legacy account, so once `AccountKey20DeriveCompat` is off the governance
+		/// splits its funds to the paras which funded it.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::migrate_legacy_account()
+		)]
+		pub fn migrate_legacy_account(
+			origin: OriginFor<T>,
+			location: MultiLocation,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ConfigModifierOrigin::ensure_origin(origin)?;
+
+			let key = match location {
+				MultiLocation {
+					parents: 1,
+					interior: X2(Parachain(_), AccountKey20 { key, .. }),
+				} => key,
+				_ => Err(Error::<T>::NoLegacyAccount)?,
+			};
+			let account = T::LocationToAccountId::convert_ref(&location)
+				.map_err(|_| Error::<T>::AccountIdConversionFailed)?;
+			let legacy_account = T::LocationToAccountId::convert(MultiLocation::new(
+				0,
+				X1(AccountKey20 { network: NetworkId::Any, key }),
+			))
+			.map_err(|_| Error::<T>::AccountIdConversionFailed)?;
+
+			ensure!(legacy_account != account, Error::<T>::NoLegacyAccount);
+
+			T::RingCurrency::transfer(
+				&legacy_account,
+				&account,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::LegacyAccountMigrated {
+				location,
+				legacy_account,
+				account,
+				amount,
+			});
+
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn do_forward(
+			origin: OriginFor<T>,
+			target: Target,
+			remote_xcm: Xcm<<T as frame_system::Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			Self::do_forward_batch(origin, vec![(target, remote_xcm)])
+		}
+
+		/// Pay the fees of all the messages in one local xcm execution, then send them.
+		fn do_forward_batch(
+			origin: OriginFor<T>,
+			messages: Vec<(Target, Xcm<<T as frame_system::Config>::Call>)>,
 		) -> DispatchResultWithPostInfo {
 			// MultiLocation origin used to execute xcm
 			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin.clone())?;
 			let account_id = ensure_signed(origin)?;
+
+			Self::ensure_caller_allowed(&account_id)?;
+
 			// U8 account used in DescendOrigin instruction
 			let raw_account = <[u8; 32]>::try_from(account_id.encode())
 				.map_err(|_| Error::<T>::AccountIdConversionFailed)?;
+			let mut local_xcm = Xcm(Vec::with_capacity(messages.len()));
+			let mut forwards = Vec::with_capacity(messages.len());
 
-			let mut remote_xcm: Xcm<<T as frame_system::Config>::Call> =
-				(*message).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			for (target, remote_xcm) in messages {
+				Self::note_forward(&account_id)?;
 
-			// Calculate the execution fee required for remote xcm execution
-			// fee = fee_per_second * (weight/weight_per_second)
-			let local_asset_units_per_second: AssetUnitsPerSecond;
-			let remote_weight: Weight;
-			match target {
-				Target::Moonbeam => {
-					local_asset_units_per_second =
-						TargetXcmExecConfig::<T>::get(T::MoonbeamLocation::get())
-							.ok_or(Error::<T>::TargetXcmExecNotConfig)?;
-					remote_weight = T::MoonbeamWeigher::weight(&mut Self::extend_remote_xcm(
-						raw_account,
-						remote_xcm.clone(),
-						MultiAsset { id: AssetId::from(T::LocalAssetId::get()), fun: Fungible(0) },
-					))
-					.map_err(|()| Error::<T>::UnweighableMessage)?;
-				},
-				Target::Astar => {
-					local_asset_units_per_second =
-						TargetXcmExecConfig::<T>::get(T::AstarLocation::get())
-							.ok_or(Error::<T>::TargetXcmExecNotConfig)?;
-					remote_weight = T::AstarWeigher::weight(&mut Self::extend_remote_xcm(
+				let target_location = Self::target_location(&target);
+
+				// Calculate the execution fee required for remote xcm execution
+				// fee = fee_per_second * (weight/weight_per_second)
+				let local_asset_units_per_second = TargetXcmExecConfig::<T>::get(&target_location)
+					.ok_or(Error::<T>::TargetXcmExecNotConfig)?;
+				ensure!(
+					!Self::is_units_per_second_stale(&target_location),
+					Error::<T>::StaleUnitsPerSecond
+				);
+				let remote_weight = Self::weigh_remote_xcm(
+					&target,
+					&mut Self::extend_remote_xcm(
 						raw_account,
 						remote_xcm.clone(),
 						MultiAsset { id: AssetId::from(T::LocalAssetId::get()), fun: Fungible(0) },
-					))
-					.map_err(|()| Error::<T>::UnweighableMessage)?;
-				},
-			}
-			let amount = local_asset_units_per_second.saturating_mul(remote_weight as u128)
-				/ (WEIGHT_PER_SECOND as u128);
-			let remote_xcm_fee =
-				MultiAsset { id: AssetId::from(T::LocalAssetId::get()), fun: Fungible(amount) };
+					),
+				)
+				.map_err(|()| Error::<T>::UnweighableMessage)?;
+				let amount = local_asset_units_per_second.saturating_mul(remote_weight as u128)
+					/ (WEIGHT_PER_SECOND as u128);
+				let remote_xcm_fee =
+					MultiAsset { id: AssetId::from(T::LocalAssetId::get()), fun: Fungible(amount) };
 
-			// Transfer xcm execution fee to target sovereign account
-			let mut local_xcm;
-			match target {
-				Target::Moonbeam => {
-					local_xcm = Xcm(vec![TransferAsset {
-						assets: remote_xcm_fee.clone().into(),
-						beneficiary: T::MoonbeamLocation::get(),
-					}]);
-				},
-				Target::Astar => {
-					local_xcm = Xcm(vec![TransferAsset {
-						assets: remote_xcm_fee.clone().into(),
-						beneficiary: T::AstarLocation::get(),
-					}]);
-				},
+				// Transfer xcm execution fee to target sovereign account
+				local_xcm.0.push(TransferAsset {
+					assets: remote_xcm_fee.clone().into(),
+					beneficiary: target_location.clone(),
+				});
+				forwards.push((
+					target,
+					target_location,
+					remote_xcm,
+					remote_weight,
+					remote_xcm_fee,
+					amount,
+				));
 			}
+
 			let local_weight = T::LocalWeigher::weight(&mut local_xcm)
 				.map_err(|()| Error::<T>::UnweighableMessage)?;
 			T::XcmExecutor::execute_xcm_in_credit(
@@ -227,50 +882,115 @@ pub mod pallet {
 				Error::<T>::FailedPayXcmFee
 			})?;
 
-			// Toggle the xcm_fee relative to a target context
 			let ancestry = T::LocationInverter::ancestry();
-			let mut remote_xcm_fee_anchor_dest = remote_xcm_fee;
-			match target {
-				Target::Moonbeam => {
-					remote_xcm_fee_anchor_dest
-						.reanchor(&T::MoonbeamLocation::get(), &ancestry)
-						.map_err(|()| Error::<T>::MultiLocationFull)?;
-					remote_xcm = Self::extend_remote_xcm(
-						raw_account,
-						remote_xcm,
-						remote_xcm_fee_anchor_dest,
-					);
-					// Send remote xcm to target
-					T::XcmSender::send_xcm(T::MoonbeamLocation::get(), remote_xcm.clone().into())
-						.map_err(|_| Error::<T>::XcmSendFailed)?;
-				},
-				Target::Astar => {
-					remote_xcm_fee_anchor_dest
-						.reanchor(&T::AstarLocation::get(), &ancestry)
-						.map_err(|()| Error::<T>::MultiLocationFull)?;
-					remote_xcm = Self::extend_remote_xcm(
-						raw_account,
-						remote_xcm,
-						remote_xcm_fee_anchor_dest,
-					);
-					// Send remote xcm to target
-					T::XcmSender::send_xcm(T::AstarLocation::get(), remote_xcm.clone().into())
-						.map_err(|_| Error::<T>::XcmSendFailed)?;
-				},
+
+			for (target, target_location, remote_xcm, remote_weight, remote_xcm_fee, amount) in
+				forwards
+			{
+				// Toggle the xcm_fee relative to a target context
+				let mut remote_xcm_fee_anchor_dest = remote_xcm_fee;
+				remote_xcm_fee_anchor_dest
+					.reanchor(&target_location, &ancestry)
+					.map_err(|()| Error::<T>::MultiLocationFull)?;
+				let remote_xcm =
+					Self::extend_remote_xcm(raw_account, remote_xcm, remote_xcm_fee_anchor_dest);
+				// Send remote xcm to target
+				T::XcmSender::send_xcm(target_location, remote_xcm.clone().into())
+					.map_err(|_| Error::<T>::XcmSendFailed)?;
+
+				Self::deposit_event(Event::ForwardTo(
+					origin_location.clone(),
+					target,
+					remote_xcm.into(),
+					remote_weight,
+					amount,
+				));
 			}
 
-			Self::deposit_event(Event::ForwardTo(
-				origin_location,
-				target,
-				remote_xcm.into(),
-				remote_weight,
-				amount,
-			));
 			Ok(().into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
+		/// Check the caller against the forward policy.
+		fn ensure_caller_allowed(account_id: &T::AccountId) -> DispatchResult {
+			let allowed = match ForwardPolicy::<T>::get() {
+				CallerPolicy::Open => true,
+				CallerPolicy::Allowlisted =>
+					AllowedCallers::<T>::contains_key(account_id)
+						|| AllowedBridgedCallers::<T>::contains_key(account_id),
+				CallerPolicy::BridgedOnly => AllowedBridgedCallers::<T>::contains_key(account_id),
+			};
+
+			ensure!(allowed, Error::<T>::CallerNotAllowed);
+
+			Ok(())
+		}
+
+		/// Count the forward of the caller, fail if it exceeds the rate limit.
+		fn note_forward(account_id: &T::AccountId) -> DispatchResult {
+			if let Some(ForwardRateLimit { period, max_forwards }) = RateLimit::<T>::get() {
+				let now = <frame_system::Pallet<T>>::block_number();
+				let period_start = now.saturating_sub(now % period);
+
+				ForwardCount::<T>::try_mutate(account_id, |maybe_count| {
+					let count = match maybe_count {
+						Some((start, count)) if *start == period_start => *count,
+						_ => 0,
+					};
+
+					ensure!(count < max_forwards, Error::<T>::ForwardRateLimited);
+
+					*maybe_count = Some((period_start, count + 1));
+
+					Ok(())
+				})
+			} else {
+				Ok(())
+			}
+		}
+
+		/// Derive the account of a sender on the bridged chain.
+		pub fn derived_bridged_account(source_account: T::AccountId) -> T::AccountId {
+			let hex_id = derive_account_id::<T::AccountId>(
+				T::BridgedChainId::get(),
+				SourceAccount::Account(source_account),
+			);
+
+			T::BridgedAccountIdConverter::convert(hex_id)
+		}
+
+		/// Whether the units per second of the target chain is too old to be used.
+		/// Always fresh if the target has no bounds.
+		pub fn is_units_per_second_stale(target_location: &MultiLocation) -> bool {
+			match TargetXcmExecBounds::<T>::get(target_location) {
+				Some(bounds) => match TargetXcmExecConfigUpdatedAt::<T>::get(target_location) {
+					Some(updated_at) =>
+						<frame_system::Pallet<T>>::block_number().saturating_sub(updated_at)
+							> bounds.max_age,
+					None => true,
+				},
+				None => false,
+			}
+		}
+
+		/// The location of the target chain.
+		fn target_location(target: &Target) -> MultiLocation {
+			match target {
+				Target::Moonbeam => T::MoonbeamLocation::get(),
+				Target::Astar => T::AstarLocation::get(),
+			}
+		}
+
+		/// Calculate the weight required for the target chain execution of xcm.
+		fn weigh_remote_xcm(
+			target: &Target,
+			xcm: &mut Xcm<<T as frame_system::Config>::Call>,
+		) -> Result<Weight, ()> {
+			match target {
+				Target::Moonbeam => T::MoonbeamWeigher::weight(xcm),
+				Target::Astar => T::AstarWeigher::weight(xcm),
+			}
+		}
+
 		/// Extend xcm for remote execution
 		fn extend_remote_xcm(
 			raw_account: [u8; 32],
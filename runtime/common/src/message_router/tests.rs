@@ -0,0 +1,1293 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// --- crates.io ---
+use ethereum_types::{H160, U256};
+// --- paritytech ---
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResultWithPostInfo,
+	traits::{ConstBool, Get},
+	weights::{constants::WEIGHT_PER_SECOND, GetDispatchInfo, Weight},
+};
+use sp_runtime::{DispatchError::BadOrigin, Perbill};
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_builder::Account32Hash;
+use xcm_executor::traits::{Convert, ShouldExecute};
+use xcm_simulator::TestExt;
+// --- darwinia-network ---
+use crate::{
+	dvm::{self, checksum_of},
+	message_router::{
+		barriers::AllowDescendOriginPaidExecutionFrom,
+		ethereum_xcm::{encode_transact, EthereumXcmConfig, MAX_ETHEREUM_XCM_INPUT_SIZE},
+		location_conversion::{AccountKey20Derive, SiblingAccountKey20Derive},
+		mock::*,
+		CallerPolicy, Error, Event as MessageRouterEvent, FeedBounds, ForwardRateLimit, Target,
+	},
+};
+
+type DescendOriginBarrier = AllowDescendOriginPaidExecutionFrom<darwinia::AllowDescendOrigin>;
+
+const REMARK: &[u8] = b"Darwinia";
+const REQUIRE_WEIGHT_AT_MOST: Weight = 1_000_000_000;
+
+fn sibling_remark() -> sibling::Call {
+	sibling::Call::System(frame_system::Call::remark_with_event { remark: REMARK.to_vec() })
+}
+
+fn darwinia_remark() -> darwinia::Call {
+	darwinia::Call::System(frame_system::Call::remark_with_event { remark: REMARK.to_vec() })
+}
+
+/// The xcm of the `forward`, which makes a remark on the sibling chain.
+fn transact_sibling_remark() -> Box<VersionedXcm<darwinia::Call>> {
+	Box::new(VersionedXcm::from(Xcm(vec![Transact {
+		origin_type: OriginKind::SovereignAccount,
+		require_weight_at_most: REQUIRE_WEIGHT_AT_MOST,
+		call: sibling_remark().encode().into(),
+	}])))
+}
+
+/// The weight of the extended remote xcm on the sibling chain.
+///
+/// `ReserveAssetDeposited`, `BuyExecution`, `SetAppendix` with `RefundSurplus` and
+/// `DepositAsset`, `DescendOrigin` and `Transact`.
+fn remote_weight() -> Weight {
+	7 * sibling::UnitWeightCost::get() + REQUIRE_WEIGHT_AT_MOST
+}
+
+fn remote_fee(units_per_second: u128) -> Balance {
+	units_per_second * remote_weight() as u128 / WEIGHT_PER_SECOND as u128
+}
+
+/// The sibling chain refunds the unused weight of the `Transact`.
+fn remote_refund() -> Balance {
+	let surplus = REQUIRE_WEIGHT_AT_MOST - sibling_remark().get_dispatch_info().weight;
+
+	DARWINIA_TOKEN_UNITS_PER_SECOND * surplus as u128 / WEIGHT_PER_SECOND as u128
+}
+
+fn set_units_per_second(para_id: u32, units_per_second: u128) {
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::set_target_xcm_exec_config(
+			darwinia::Origin::root(),
+			MultiLocation::new(1, X1(Parachain(para_id))),
+			units_per_second,
+		));
+	});
+}
+
+/// The account of a Darwinia account on the sibling chain, descended from Darwinia's location.
+fn descended_account(account: AccountId) -> AccountId {
+	Account32Hash::<sibling::RelayNetwork, AccountId>::convert(MultiLocation::new(
+		1,
+		X2(
+			Parachain(DARWINIA_PARA_ID),
+			AccountId32 { network: NetworkId::Any, id: account.into() },
+		),
+	))
+	.unwrap()
+}
+
+fn forward_remark(who: AccountId) -> DispatchResultWithPostInfo {
+	darwinia::MessageRouter::forward(
+		darwinia::Origin::signed(who),
+		Target::Moonbeam,
+		transact_sibling_remark(),
+	)
+}
+
+fn moonbeam_feed_bounds() -> FeedBounds<u64> {
+	FeedBounds {
+		min_units_per_second: 50,
+		max_units_per_second: 200,
+		max_change: Perbill::from_percent(10),
+		max_age: 5,
+	}
+}
+
+fn sibling_remarked_by(who: &AccountId) -> bool {
+	sibling::System::events().iter().any(|r| {
+		matches!(
+			&r.event,
+			sibling::Event::System(frame_system::Event::Remarked { sender, .. }) if sender == who
+		)
+	})
+}
+
+#[test]
+fn descend_origin_barrier_should_work() {
+	let mut message = Xcm::<()>(vec![
+		DescendOrigin(X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+		WithdrawAsset((Here, 100).into()),
+		ClearOrigin,
+		BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+	]);
+
+	assert_ok!(DescendOriginBarrier::should_execute(
+		&MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID))),
+		&mut message,
+		10,
+		&mut 0,
+	));
+	assert_eq!(message.0[3], BuyExecution { fees: (Here, 100).into(), weight_limit: Limited(10) });
+}
+
+#[test]
+fn descend_origin_barrier_should_reject_untrusted_origin() {
+	let mut message = Xcm::<()>(vec![
+		DescendOrigin(X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+		WithdrawAsset((Here, 100).into()),
+		BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+	]);
+
+	assert!(DescendOriginBarrier::should_execute(
+		&MultiLocation::new(1, X1(Parachain(DARWINIA_PARA_ID))),
+		&mut message,
+		10,
+		&mut 0,
+	)
+	.is_err());
+	assert!(DescendOriginBarrier::should_execute(
+		&MultiLocation::parent(),
+		&mut message,
+		10,
+		&mut 0
+	)
+	.is_err());
+}
+
+#[test]
+fn descend_origin_barrier_should_reject_unpaid_message() {
+	let origin = MultiLocation::new(1, X1(Parachain(ASTAR_PARA_ID)));
+	let descend_origin =
+		DescendOrigin(X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }));
+
+	// `DescendOrigin` must be the first instruction.
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut Xcm::<()>(vec![
+			WithdrawAsset((Here, 100).into()),
+			descend_origin.clone(),
+			BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+		]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	// The assets must be placed in the holding register before buying execution.
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut Xcm::<()>(vec![
+			descend_origin.clone(),
+			BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+		]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	// No execution bought.
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut Xcm::<()>(vec![
+			descend_origin.clone(),
+			WithdrawAsset((Here, 100).into()),
+			ClearOrigin
+		]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	// Bought less than the weight of the message.
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut Xcm::<()>(vec![
+			descend_origin,
+			WithdrawAsset((Here, 100).into()),
+			BuyExecution { fees: (Here, 100).into(), weight_limit: Limited(9) },
+		]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+}
+
+#[test]
+fn descend_origin_barrier_should_allow_account_id32_and_top_up() {
+	let mut message = Xcm::<()>(vec![
+		DescendOrigin(X1(AccountId32 { network: NetworkId::Any, id: ALICE.into() })),
+		ReserveAssetDeposited((Here, 100).into()),
+		// Paid from the descended account.
+		WithdrawAsset((Here, 100).into()),
+		ClearOrigin,
+		BuyExecution { fees: (Here, 200).into(), weight_limit: Limited(20) },
+		Transact {
+			origin_type: OriginKind::SovereignAccount,
+			require_weight_at_most: 5,
+			call: Vec::<u8>::new().into(),
+		},
+	]);
+
+	assert_ok!(DescendOriginBarrier::should_execute(
+		&MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID))),
+		&mut message,
+		10,
+		&mut 0,
+	));
+	assert_eq!(message.0[4], BuyExecution { fees: (Here, 200).into(), weight_limit: Limited(10) });
+}
+
+#[test]
+fn descend_origin_barrier_should_reject_invalid_descend_origin() {
+	let origin = MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)));
+
+	for interior in [
+		X1(Parachain(ASTAR_PARA_ID)),
+		X2(Parachain(ASTAR_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	] {
+		assert!(DescendOriginBarrier::should_execute(
+			&origin,
+			&mut Xcm::<()>(vec![
+				DescendOrigin(interior),
+				WithdrawAsset((Here, 100).into()),
+				BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+			]),
+			10,
+			&mut 0,
+		)
+		.is_err());
+	}
+
+	// Only one `WithdrawAsset` from the descended account.
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut Xcm::<()>(vec![
+			DescendOrigin(X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+			WithdrawAsset((Here, 100).into()),
+			WithdrawAsset((Here, 100).into()),
+			WithdrawAsset((Here, 100).into()),
+			BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+		]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+}
+
+#[test]
+fn descend_origin_barrier_should_reject_overweight_transact() {
+	let transact = |require_weight_at_most| Transact {
+		origin_type: OriginKind::SovereignAccount,
+		require_weight_at_most,
+		call: Vec::<u8>::new().into(),
+	};
+	let message = |transacts: Vec<xcm::latest::Instruction<()>>| {
+		let mut instructions = vec![
+			DescendOrigin(X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+			WithdrawAsset((Here, 100).into()),
+			BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+		];
+
+		instructions.extend(transacts);
+
+		Xcm::<()>(instructions)
+	};
+	let origin = MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)));
+
+	assert_ok!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut message(vec![transact(5), transact(5)]),
+		10,
+		&mut 0,
+	));
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut message(vec![transact(11)]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut message(vec![transact(5), transact(6)]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	// The nested `Transact`s are counted too.
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut message(vec![transact(5), SetAppendix(Xcm(vec![transact(6)]))]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	assert!(DescendOriginBarrier::should_execute(
+		&origin,
+		&mut message(vec![SetErrorHandler(Xcm(vec![SetAppendix(Xcm(vec![transact(11)]))]))]),
+		10,
+		&mut 0,
+	)
+	.is_err());
+	// Bought the limited weight.
+	let mut limited = message(vec![transact(8)]);
+
+	limited.0[2] = BuyExecution { fees: (Here, 100).into(), weight_limit: Limited(20) };
+
+	assert_ok!(DescendOriginBarrier::should_execute(&origin, &mut limited, 10, &mut 0));
+	assert_eq!(limited.0[2], BuyExecution { fees: (Here, 100).into(), weight_limit: Limited(10) });
+}
+
+#[test]
+fn account_key20_derive_should_work() {
+	let local =
+		MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }));
+	let sibling = MultiLocation::new(
+		1,
+		X2(Parachain(MOONBEAM_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	);
+	let account: [u8; 32] = AccountKey20Derive::<AccountId>::convert(local).unwrap().into();
+
+	assert_eq!(&account[0..4], b"dvm:");
+	assert_eq!(&account[4..11], &[0; 7]);
+	assert_eq!(&account[11..31], &ALICE_KEY20);
+	assert_eq!(account[31], account[0..31].iter().fold(0, |sum, &byte| sum ^ byte));
+	assert_eq!(AccountKey20Derive::<AccountId>::convert(sibling).unwrap(), account.into());
+}
+
+#[test]
+fn account_key20_derive_should_reject_other_locations() {
+	let locations = [
+		MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID))),
+		MultiLocation::new(0, X1(AccountId32 { network: NetworkId::Any, id: ALICE.into() })),
+		MultiLocation::new(2, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+	];
+
+	for location in locations {
+		assert_eq!(AccountKey20Derive::<AccountId>::convert(location.clone()), Err(location));
+	}
+	assert_eq!(AccountKey20Derive::<AccountId>::reverse(ALICE), Err(ALICE));
+}
+
+#[test]
+fn account_key20_derive_reverse_should_work() {
+	let local =
+		MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }));
+	let account = AccountKey20Derive::<AccountId>::convert(local.clone()).unwrap();
+	let mut raw_account: [u8; 32] = account.clone().into();
+
+	assert_eq!(AccountKey20Derive::<AccountId>::reverse(account), Ok(local));
+
+	// Bad checksum.
+	raw_account[31] ^= 1;
+	assert!(AccountKey20Derive::<AccountId>::reverse(raw_account.into()).is_err());
+}
+
+#[test]
+fn account_key20_derive_reverse_should_reject_non_canonical_account() {
+	let local =
+		MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }));
+	let mut raw_account: [u8; 32] = AccountKey20Derive::<AccountId>::convert(local).unwrap().into();
+
+	// Non-zero padding with a valid checksum, which would decode to the same address.
+	raw_account[4] = 1;
+	raw_account[31] = checksum_of(&raw_account);
+
+	assert!(dvm::decode(&raw_account).is_none());
+	assert!(AccountKey20Derive::<AccountId>::reverse(raw_account.into()).is_err());
+}
+
+#[test]
+fn sibling_account_key20_derive_should_mix_para_id() {
+	type Derive = SiblingAccountKey20Derive<AccountId, ConstBool<false>>;
+
+	let moonbeam = MultiLocation::new(
+		1,
+		X2(Parachain(MOONBEAM_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	);
+	let astar = MultiLocation::new(
+		1,
+		X2(Parachain(ASTAR_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	);
+	let local =
+		MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }));
+	let moonbeam_account = Derive::convert(moonbeam.clone()).unwrap();
+	let astar_account = Derive::convert(astar.clone()).unwrap();
+	let raw_account: [u8; 32] = moonbeam_account.clone().into();
+
+	assert_ne!(moonbeam_account, astar_account);
+	assert_eq!(&raw_account[0..4], b"sibl");
+	assert_eq!(&raw_account[4..8], &MOONBEAM_PARA_ID.to_le_bytes());
+	assert_eq!(&raw_account[11..31], &ALICE_KEY20);
+	// The local AccountKey20 is still derived to the dvm account.
+	assert_eq!(
+		Derive::convert(local.clone()).unwrap(),
+		AccountKey20Derive::<AccountId>::convert(local).unwrap()
+	);
+	assert_eq!(Derive::reverse(moonbeam_account), Ok(moonbeam));
+	assert_eq!(Derive::reverse(astar_account), Ok(astar));
+}
+
+#[test]
+fn sibling_account_key20_derive_compat_should_keep_legacy_account() {
+	type Derive = SiblingAccountKey20Derive<AccountId, ConstBool<true>>;
+
+	let moonbeam = MultiLocation::new(
+		1,
+		X2(Parachain(MOONBEAM_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	);
+	let astar = MultiLocation::new(
+		1,
+		X2(Parachain(ASTAR_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	);
+
+	assert_eq!(
+		Derive::convert(moonbeam.clone()).unwrap(),
+		AccountKey20Derive::<AccountId>::convert(moonbeam).unwrap()
+	);
+	assert_eq!(
+		Derive::convert(astar.clone()).unwrap(),
+		AccountKey20Derive::<AccountId>::convert(astar).unwrap()
+	);
+}
+
+#[test]
+fn sibling_account_key20_derive_reverse_should_reject_invalid_account() {
+	type Derive = SiblingAccountKey20Derive<AccountId, ConstBool<false>>;
+
+	let mut raw_account: [u8; 32] = Derive::convert(MultiLocation::new(
+		1,
+		X2(Parachain(MOONBEAM_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	))
+	.unwrap()
+	.into();
+
+	// Bad checksum.
+	raw_account[31] ^= 1;
+	assert!(Derive::reverse(raw_account.into()).is_err());
+	assert_eq!(Derive::reverse(ALICE), Err(ALICE));
+}
+
+#[test]
+fn location_to_account_id_should_reverse_derived_accounts() {
+	let locations = [
+		MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID))),
+		MultiLocation::new(0, X1(AccountId32 { network: NetworkId::Any, id: ALICE.into() })),
+		MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+		MultiLocation::new(
+			1,
+			X2(
+				Parachain(MOONBEAM_PARA_ID),
+				AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 },
+			),
+		),
+	];
+
+	for location in locations {
+		let account = darwinia::LocationToAccountId::convert_ref(&location).unwrap();
+
+		assert_eq!(darwinia::LocationToAccountId::reverse(account), Ok(location));
+	}
+}
+
+#[test]
+fn migrate_legacy_account_should_work() {
+	MockNet::reset();
+
+	Darwinia::execute_with(|| {
+		let local =
+			MultiLocation::new(0, X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }));
+		let legacy_account = AccountKey20Derive::<AccountId>::convert(local.clone()).unwrap();
+		let location = MultiLocation::new(
+			1,
+			X2(
+				Parachain(MOONBEAM_PARA_ID),
+				AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 },
+			),
+		);
+		let account = darwinia::account_key20_derived(MOONBEAM_PARA_ID, ALICE_KEY20);
+
+		assert_ok!(darwinia::Balances::transfer(
+			darwinia::Origin::signed(ALICE),
+			legacy_account.clone(),
+			2 * UNIT
+		));
+
+		// Only the governance migrates, a sibling chain account can not take the shared funds.
+		assert_noop!(
+			darwinia::MessageRouter::migrate_legacy_account(
+				darwinia::Origin::signed(account.clone()),
+				location.clone(),
+				UNIT
+			),
+			BadOrigin
+		);
+		// Only to the accounts derived from a sibling chain AccountKey20.
+		assert_noop!(
+			darwinia::MessageRouter::migrate_legacy_account(darwinia::Origin::root(), local, UNIT),
+			Error::<darwinia::Runtime>::NoLegacyAccount
+		);
+
+		// The funds from the other sibling chains stay in the legacy account.
+		assert_ok!(darwinia::MessageRouter::migrate_legacy_account(
+			darwinia::Origin::root(),
+			location.clone(),
+			UNIT
+		));
+		assert_eq!(darwinia::Balances::free_balance(&legacy_account), UNIT);
+		assert_eq!(darwinia::Balances::free_balance(&account), INITIAL_BALANCE + UNIT);
+		assert!(darwinia::System::events().iter().any(|r| matches!(
+			&r.event,
+			darwinia::Event::MessageRouter(MessageRouterEvent::LegacyAccountMigrated {
+				location: l, amount, ..
+			}) if *l == location && *amount == UNIT
+		)));
+	});
+}
+
+#[test]
+fn forward_should_pay_fee_to_target_sovereign_account() {
+	MockNet::reset();
+
+	let fee = remote_fee(DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::forward(
+			darwinia::Origin::signed(ALICE),
+			Target::Moonbeam,
+			transact_sibling_remark(),
+		));
+		assert_eq!(darwinia::Balances::free_balance(&ALICE), INITIAL_BALANCE - fee);
+		assert_eq!(darwinia::Balances::free_balance(&sibling_account_id(MOONBEAM_PARA_ID)), fee);
+		assert!(darwinia::System::events().iter().any(|r| matches!(
+			&r.event,
+			darwinia::Event::MessageRouter(MessageRouterEvent::ForwardTo(
+				_,
+				Target::Moonbeam,
+				_,
+				weight,
+				amount
+			)) if *weight == remote_weight() && *amount == fee
+		)));
+	});
+}
+
+#[test]
+fn forward_should_descend_origin_on_target() {
+	MockNet::reset();
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::forward(
+			darwinia::Origin::signed(ALICE),
+			Target::Moonbeam,
+			transact_sibling_remark(),
+		));
+	});
+
+	Moonbeam::execute_with(|| {
+		assert!(sibling_remarked_by(&descended_account(ALICE)));
+		assert!(!sibling_remarked_by(&sibling_account_id(DARWINIA_PARA_ID)));
+	});
+}
+
+#[test]
+fn forward_should_refund_surplus_to_sovereign_account() {
+	MockNet::reset();
+
+	set_units_per_second(ASTAR_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::forward(
+			darwinia::Origin::signed(ALICE),
+			Target::Astar,
+			transact_sibling_remark(),
+		));
+	});
+
+	Astar::execute_with(|| {
+		assert!(remote_refund() > 0);
+		assert_eq!(
+			sibling::Balances::free_balance(&sibling_account_id(DARWINIA_PARA_ID)),
+			remote_refund()
+		);
+		// Nothing left to the descended account.
+		assert_eq!(sibling::Balances::free_balance(&descended_account(ALICE)), 0);
+	});
+}
+
+#[test]
+fn forward_should_fail_if_target_not_configured() {
+	MockNet::reset();
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::forward(
+				darwinia::Origin::signed(ALICE),
+				Target::Moonbeam,
+				transact_sibling_remark(),
+			),
+			Error::<darwinia::Runtime>::TargetXcmExecNotConfig
+		);
+	});
+}
+
+#[test]
+fn forward_should_fail_if_fee_not_paid() {
+	MockNet::reset();
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::forward(
+				darwinia::Origin::signed(BOB),
+				Target::Moonbeam,
+				transact_sibling_remark(),
+			),
+			Error::<darwinia::Runtime>::FailedPayXcmFee
+		);
+	});
+
+	Moonbeam::execute_with(|| {
+		assert!(!sibling_remarked_by(&descended_account(BOB)));
+	});
+}
+
+#[test]
+fn underpaid_forward_should_fail_on_target() {
+	MockNet::reset();
+
+	// Charge less than the target requires.
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND / 2);
+
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::forward(
+			darwinia::Origin::signed(ALICE),
+			Target::Moonbeam,
+			transact_sibling_remark(),
+		));
+		assert_eq!(
+			darwinia::Balances::free_balance(&ALICE),
+			INITIAL_BALANCE - remote_fee(DARWINIA_TOKEN_UNITS_PER_SECOND / 2)
+		);
+	});
+
+	Moonbeam::execute_with(|| {
+		assert!(!sibling_remarked_by(&descended_account(ALICE)));
+		assert!(sibling::System::events().iter().any(|r| matches!(
+			r.event,
+			sibling::Event::MsgQueue(mock_msg_queue::Event::Fail(_, XcmError::TooExpensive))
+		)));
+	});
+}
+
+#[test]
+fn forward_batch_should_work() {
+	MockNet::reset();
+
+	let fee = remote_fee(DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+	set_units_per_second(ASTAR_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::forward_batch(
+			darwinia::Origin::signed(ALICE),
+			vec![
+				(Target::Moonbeam, transact_sibling_remark()),
+				(Target::Astar, transact_sibling_remark())
+			],
+		));
+		assert_eq!(darwinia::Balances::free_balance(&ALICE), INITIAL_BALANCE - 2 * fee);
+		assert_eq!(darwinia::Balances::free_balance(&sibling_account_id(MOONBEAM_PARA_ID)), fee);
+		assert_eq!(darwinia::Balances::free_balance(&sibling_account_id(ASTAR_PARA_ID)), fee);
+	});
+
+	Moonbeam::execute_with(|| assert!(sibling_remarked_by(&descended_account(ALICE))));
+	Astar::execute_with(|| assert!(sibling_remarked_by(&descended_account(ALICE))));
+}
+
+#[test]
+fn forward_batch_should_send_nothing_if_any_fails() {
+	MockNet::reset();
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::forward_batch(
+				darwinia::Origin::signed(ALICE),
+				vec![
+					(Target::Moonbeam, transact_sibling_remark()),
+					(Target::Astar, transact_sibling_remark())
+				],
+			),
+			Error::<darwinia::Runtime>::TargetXcmExecNotConfig
+		);
+	});
+
+	Moonbeam::execute_with(|| assert!(!sibling_remarked_by(&descended_account(ALICE))));
+}
+
+#[test]
+fn sibling_account_should_descend_origin_to_derived_account() {
+	MockNet::reset();
+
+	let derived = darwinia::account_key20_derived(MOONBEAM_PARA_ID, ALICE_KEY20);
+	let fee = 10 * darwinia::UnitWeightCost::get() as Balance;
+
+	Moonbeam::execute_with(|| {
+		assert_ok!(sibling::XcmRouter::send_xcm(
+			MultiLocation::new(1, X1(Parachain(DARWINIA_PARA_ID))),
+			Xcm(vec![
+				DescendOrigin(X1(AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 })),
+				WithdrawAsset((darwinia::AnchoringSelfReserve::get(), fee).into()),
+				BuyExecution {
+					fees: (darwinia::AnchoringSelfReserve::get(), fee).into(),
+					weight_limit: Unlimited,
+				},
+				Transact {
+					origin_type: OriginKind::SovereignAccount,
+					require_weight_at_most: REQUIRE_WEIGHT_AT_MOST,
+					call: darwinia_remark().encode().into(),
+				},
+			]),
+		));
+	});
+
+	Darwinia::execute_with(|| {
+		assert!(darwinia::System::events().iter().any(|r| matches!(
+			&r.event,
+			darwinia::Event::System(frame_system::Event::Remarked { sender, .. }) if *sender == derived
+		)));
+		assert_eq!(darwinia::Balances::free_balance(&derived), INITIAL_BALANCE - fee);
+	});
+}
+
+#[test]
+fn encode_transact_should_work() {
+	let config = EthereumXcmConfig { pallet_index: 38, call_index: 0, weight_per_gas: 25_000 };
+	let contract = H160::repeat_byte(1);
+	let input = vec![1, 2, 3];
+
+	assert_eq!(
+		encode_transact(&config, contract, input.clone(), 100, U256::from(10)),
+		[
+			// `EthereumXcm::transact`, `EthereumXcmTransaction::V2`.
+			vec![38, 0, 1],
+			U256::from(100).encode(),
+			// `TransactionAction::Call`.
+			vec![0],
+			contract.0.to_vec(),
+			U256::from(10).encode(),
+			input.encode(),
+			// No access list.
+			vec![0],
+		]
+		.concat()
+	);
+}
+
+#[test]
+fn forward_evm_call_should_work() {
+	MockNet::reset();
+
+	let config = EthereumXcmConfig { pallet_index: 38, call_index: 0, weight_per_gas: 25_000 };
+	let contract = H160::repeat_byte(1);
+	let gas_limit = 10_000;
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::forward_evm_call(
+				darwinia::Origin::signed(ALICE),
+				Target::Moonbeam,
+				contract,
+				vec![],
+				gas_limit,
+				U256::zero(),
+			),
+			Error::<darwinia::Runtime>::TargetNotEvmCapable
+		);
+
+		assert_noop!(
+			darwinia::MessageRouter::set_target_ethereum_xcm_config(
+				darwinia::Origin::signed(ALICE),
+				MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID))),
+				Some(config),
+			),
+			BadOrigin
+		);
+		assert_ok!(darwinia::MessageRouter::set_target_ethereum_xcm_config(
+			darwinia::Origin::root(),
+			MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID))),
+			Some(config),
+		));
+		assert_noop!(
+			darwinia::MessageRouter::forward_evm_call(
+				darwinia::Origin::signed(ALICE),
+				Target::Moonbeam,
+				contract,
+				vec![0; MAX_ETHEREUM_XCM_INPUT_SIZE as usize + 1],
+				gas_limit,
+				U256::zero(),
+			),
+			Error::<darwinia::Runtime>::EvmInputTooLarge
+		);
+
+		assert_ok!(darwinia::MessageRouter::forward_evm_call(
+			darwinia::Origin::signed(ALICE),
+			Target::Moonbeam,
+			contract,
+			vec![1, 2, 3],
+			gas_limit,
+			U256::zero(),
+		));
+
+		let transact: Instruction<()> = Transact {
+			origin_type: OriginKind::SovereignAccount,
+			require_weight_at_most: gas_limit * config.weight_per_gas,
+			call: encode_transact(&config, contract, vec![1, 2, 3], gas_limit, U256::zero()).into(),
+		};
+		let weight = 7 * sibling::UnitWeightCost::get() + gas_limit * config.weight_per_gas;
+
+		assert!(darwinia::System::events().iter().any(|r| matches!(
+			&r.event,
+			darwinia::Event::MessageRouter(MessageRouterEvent::ForwardTo(
+				_,
+				Target::Moonbeam,
+				xcm,
+				w,
+				_
+			)) if xcm.0.last() == Some(&transact) && *w == weight
+		)));
+	});
+}
+
+#[test]
+fn feed_target_xcm_exec_config_should_respect_bounds() {
+	MockNet::reset();
+
+	let moonbeam = MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)));
+
+	set_units_per_second(MOONBEAM_PARA_ID, 100);
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::feed_target_xcm_exec_config(
+				darwinia::Origin::root(),
+				moonbeam.clone(),
+				105,
+			),
+			Error::<darwinia::Runtime>::TargetXcmExecBoundsNotConfig
+		);
+		assert_ok!(darwinia::MessageRouter::set_target_xcm_exec_bounds(
+			darwinia::Origin::root(),
+			moonbeam.clone(),
+			Some(moonbeam_feed_bounds()),
+		));
+
+		assert_noop!(
+			darwinia::MessageRouter::feed_target_xcm_exec_config(
+				darwinia::Origin::signed(ALICE),
+				moonbeam.clone(),
+				105,
+			),
+			BadOrigin
+		);
+		// More than the max change.
+		for units_per_second in [89, 111] {
+			assert_noop!(
+				darwinia::MessageRouter::feed_target_xcm_exec_config(
+					darwinia::Origin::root(),
+					moonbeam.clone(),
+					units_per_second,
+				),
+				Error::<darwinia::Runtime>::UnitsPerSecondOutOfBounds
+			);
+		}
+
+		// The dedicated feeder.
+		assert_ok!(darwinia::MessageRouter::feed_target_xcm_exec_config(
+			darwinia::Origin::signed(BOB),
+			moonbeam.clone(),
+			110,
+		));
+		darwinia::System::assert_last_event(
+			MessageRouterEvent::<darwinia::Runtime>::TargetXcmExecConfigFed {
+				target_location: moonbeam.clone(),
+				local_asset_units_per_second: 110,
+			}
+			.into(),
+		);
+		assert_eq!(darwinia::MessageRouter::target_xcm_exec_config(&moonbeam), Some(110));
+
+		// Out of the min and max, even within the max change.
+		assert_ok!(darwinia::MessageRouter::set_target_xcm_exec_config(
+			darwinia::Origin::root(),
+			moonbeam.clone(),
+			195,
+		));
+		assert_noop!(
+			darwinia::MessageRouter::feed_target_xcm_exec_config(
+				darwinia::Origin::root(),
+				moonbeam.clone(),
+				201,
+			),
+			Error::<darwinia::Runtime>::UnitsPerSecondOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn set_target_xcm_exec_bounds_should_reject_min_above_max() {
+	MockNet::reset();
+
+	let moonbeam = MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)));
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::set_target_xcm_exec_bounds(
+				darwinia::Origin::root(),
+				moonbeam.clone(),
+				Some(FeedBounds { min_units_per_second: 201, ..moonbeam_feed_bounds() }),
+			),
+			Error::<darwinia::Runtime>::InvalidFeedBounds
+		);
+		assert_ok!(darwinia::MessageRouter::set_target_xcm_exec_bounds(
+			darwinia::Origin::root(),
+			moonbeam.clone(),
+			Some(FeedBounds { min_units_per_second: 200, ..moonbeam_feed_bounds() }),
+		));
+	});
+}
+
+#[test]
+fn target_chain_should_only_feed_itself() {
+	MockNet::reset();
+
+	let moonbeam = MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)));
+	let astar = MultiLocation::new(1, X1(Parachain(ASTAR_PARA_ID)));
+
+	set_units_per_second(MOONBEAM_PARA_ID, 100);
+	set_units_per_second(ASTAR_PARA_ID, 100);
+
+	Darwinia::execute_with(|| {
+		for location in [&moonbeam, &astar] {
+			assert_ok!(darwinia::MessageRouter::set_target_xcm_exec_bounds(
+				darwinia::Origin::root(),
+				location.clone(),
+				Some(moonbeam_feed_bounds()),
+			));
+		}
+
+		let moonbeam_origin = darwinia::Origin::from(pallet_xcm::Origin::Xcm(moonbeam.clone()));
+
+		assert_noop!(
+			darwinia::MessageRouter::feed_target_xcm_exec_config(
+				moonbeam_origin.clone(),
+				astar,
+				105,
+			),
+			Error::<darwinia::Runtime>::FeederLocationMismatch
+		);
+		assert_ok!(darwinia::MessageRouter::feed_target_xcm_exec_config(
+			moonbeam_origin,
+			moonbeam.clone(),
+			105,
+		));
+		assert_eq!(darwinia::MessageRouter::target_xcm_exec_config(&moonbeam), Some(105));
+	});
+}
+
+#[test]
+fn forward_should_fail_if_units_per_second_stale() {
+	MockNet::reset();
+
+	let moonbeam = MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)));
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_ok!(darwinia::MessageRouter::set_target_xcm_exec_bounds(
+			darwinia::Origin::root(),
+			moonbeam.clone(),
+			Some(FeedBounds {
+				min_units_per_second: 0,
+				max_units_per_second: u128::MAX,
+				..moonbeam_feed_bounds()
+			}),
+		));
+
+		// Updated at block 1, still fresh at the `max_age`.
+		darwinia::System::set_block_number(6);
+		assert!(!darwinia::MessageRouter::is_units_per_second_stale(&moonbeam));
+		assert_ok!(forward_remark(ALICE));
+
+		darwinia::System::set_block_number(7);
+		assert!(darwinia::MessageRouter::is_units_per_second_stale(&moonbeam));
+		assert_noop!(forward_remark(ALICE), Error::<darwinia::Runtime>::StaleUnitsPerSecond);
+
+		// Fresh again once fed.
+		assert_ok!(darwinia::MessageRouter::feed_target_xcm_exec_config(
+			darwinia::Origin::root(),
+			moonbeam,
+			DARWINIA_TOKEN_UNITS_PER_SECOND,
+		));
+		assert_ok!(forward_remark(ALICE));
+	});
+}
+
+#[test]
+fn forward_policy_should_work() {
+	MockNet::reset();
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		let derived = darwinia::MessageRouter::derived_bridged_account(BOB);
+
+		assert_ok!(darwinia::Balances::transfer(
+			darwinia::Origin::signed(ALICE),
+			derived.clone(),
+			UNIT
+		));
+
+		assert_noop!(
+			darwinia::MessageRouter::set_forward_policy(
+				darwinia::Origin::signed(ALICE),
+				CallerPolicy::Allowlisted
+			),
+			BadOrigin
+		);
+		assert_ok!(darwinia::MessageRouter::set_forward_policy(
+			darwinia::Origin::root(),
+			CallerPolicy::Allowlisted
+		));
+		assert_noop!(forward_remark(ALICE), Error::<darwinia::Runtime>::CallerNotAllowed);
+		assert_noop!(forward_remark(derived.clone()), Error::<darwinia::Runtime>::CallerNotAllowed);
+
+		assert_ok!(darwinia::MessageRouter::set_allowed_caller(
+			darwinia::Origin::root(),
+			ALICE,
+			true
+		));
+		assert_ok!(darwinia::MessageRouter::set_allowed_bridged_caller(
+			darwinia::Origin::root(),
+			BOB,
+			true
+		));
+		assert_ok!(forward_remark(ALICE));
+		assert_ok!(forward_remark(derived.clone()));
+
+		// Only the bridged callers.
+		assert_ok!(darwinia::MessageRouter::set_forward_policy(
+			darwinia::Origin::root(),
+			CallerPolicy::BridgedOnly
+		));
+		assert_noop!(forward_remark(ALICE), Error::<darwinia::Runtime>::CallerNotAllowed);
+		assert_ok!(forward_remark(derived.clone()));
+
+		assert_ok!(darwinia::MessageRouter::set_allowed_bridged_caller(
+			darwinia::Origin::root(),
+			BOB,
+			false
+		));
+		assert_noop!(forward_remark(derived), Error::<darwinia::Runtime>::CallerNotAllowed);
+	});
+}
+
+#[test]
+fn forward_rate_limit_should_work() {
+	MockNet::reset();
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+	set_units_per_second(ASTAR_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::set_forward_rate_limit(
+				darwinia::Origin::root(),
+				Some(ForwardRateLimit { period: 0, max_forwards: 1 })
+			),
+			Error::<darwinia::Runtime>::InvalidRateLimit
+		);
+		assert_ok!(darwinia::MessageRouter::set_forward_rate_limit(
+			darwinia::Origin::root(),
+			Some(ForwardRateLimit { period: 10, max_forwards: 1 })
+		));
+
+		assert_ok!(forward_remark(ALICE));
+		assert_noop!(forward_remark(ALICE), Error::<darwinia::Runtime>::ForwardRateLimited);
+
+		// Every message of a batch is counted.
+		darwinia::System::set_block_number(10);
+		assert_noop!(
+			darwinia::MessageRouter::forward_batch(
+				darwinia::Origin::signed(ALICE),
+				vec![
+					(Target::Moonbeam, transact_sibling_remark()),
+					(Target::Astar, transact_sibling_remark())
+				],
+			),
+			Error::<darwinia::Runtime>::ForwardRateLimited
+		);
+		assert_ok!(forward_remark(ALICE));
+
+		assert_ok!(darwinia::MessageRouter::set_forward_rate_limit(darwinia::Origin::root(), None));
+		assert_ok!(forward_remark(ALICE));
+	});
+}
+
+#[test]
+fn forward_from_bridge_should_require_bridged_origin() {
+	MockNet::reset();
+
+	let fee = remote_fee(DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	set_units_per_second(MOONBEAM_PARA_ID, DARWINIA_TOKEN_UNITS_PER_SECOND);
+
+	Darwinia::execute_with(|| {
+		let derived = darwinia::MessageRouter::derived_bridged_account(BOB);
+
+		assert_ok!(darwinia::Balances::transfer(
+			darwinia::Origin::signed(ALICE),
+			derived.clone(),
+			UNIT
+		));
+
+		// The sender must be the account derived from the source account.
+		for origin in [darwinia::Origin::signed(ALICE), darwinia::Origin::signed(BOB)] {
+			assert_noop!(
+				darwinia::MessageRouter::forward_from_bridge(
+					origin,
+					BOB,
+					Target::Moonbeam,
+					transact_sibling_remark(),
+				),
+				Error::<darwinia::Runtime>::RequireBridgedOrigin
+			);
+		}
+		assert_noop!(
+			darwinia::MessageRouter::forward_from_bridge(
+				darwinia::Origin::root(),
+				BOB,
+				Target::Moonbeam,
+				transact_sibling_remark(),
+			),
+			BadOrigin
+		);
+
+		assert_ok!(darwinia::MessageRouter::forward_from_bridge(
+			darwinia::Origin::signed(derived.clone()),
+			BOB,
+			Target::Moonbeam,
+			transact_sibling_remark(),
+		));
+		darwinia::System::assert_last_event(
+			MessageRouterEvent::<darwinia::Runtime>::ForwardFromBridge {
+				source_account: BOB,
+				derived_account: derived.clone(),
+				target: Target::Moonbeam,
+			}
+			.into(),
+		);
+		assert_eq!(darwinia::Balances::free_balance(&derived), UNIT - fee);
+	});
+
+	Moonbeam::execute_with(|| assert!(sibling_remarked_by(&descended_account(derived))));
+}
+
+#[test]
+fn forward_to_bridge_should_work() {
+	MockNet::reset();
+
+	let alice = MultiLocation::new(
+		1,
+		X2(Parachain(MOONBEAM_PARA_ID), AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 }),
+	);
+
+	Darwinia::execute_with(|| {
+		// Only the sibling chain accounts.
+		for origin in [
+			darwinia::Origin::signed(ALICE),
+			darwinia::Origin::from(pallet_xcm::Origin::Xcm(MultiLocation::new(
+				1,
+				X1(Parachain(MOONBEAM_PARA_ID)),
+			))),
+			darwinia::Origin::from(pallet_xcm::Origin::Xcm(MultiLocation::new(
+				1,
+				X2(
+					Parachain(DARWINIA_PARA_ID),
+					AccountKey20 { network: NetworkId::Any, key: ALICE_KEY20 },
+				),
+			))),
+		] {
+			assert_noop!(
+				darwinia::MessageRouter::forward_to_bridge(origin, 1, 0, vec![], 0),
+				BadOrigin
+			);
+		}
+
+		assert_ok!(darwinia::MessageRouter::forward_to_bridge(
+			darwinia::Origin::from(pallet_xcm::Origin::Xcm(alice.clone())),
+			1,
+			0,
+			darwinia_remark().encode(),
+			UNIT,
+		));
+		darwinia::System::assert_last_event(
+			MessageRouterEvent::<darwinia::Runtime>::ForwardToBridge {
+				location: alice,
+				account: darwinia::account_key20_derived(MOONBEAM_PARA_ID, ALICE_KEY20),
+				lane_id: darwinia::BridgeMessageLaneId::get(),
+				nonce: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn forward_batch_should_reject_invalid_batch() {
+	MockNet::reset();
+
+	Darwinia::execute_with(|| {
+		assert_noop!(
+			darwinia::MessageRouter::forward_batch(darwinia::Origin::signed(ALICE), vec![]),
+			Error::<darwinia::Runtime>::EmptyBatch
+		);
+		assert_noop!(
+			darwinia::MessageRouter::forward_batch(
+				darwinia::Origin::signed(ALICE),
+				vec![
+					(Target::Moonbeam, transact_sibling_remark());
+					darwinia::MaxForwardBatch::get() as usize + 1
+				],
+			),
+			Error::<darwinia::Runtime>::TooManyMessages
+		);
+	});
+}
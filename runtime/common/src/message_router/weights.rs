@@ -57,17 +57,35 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_target_xcm_exec_config() -> Weight;
 	fn forward() -> Weight;
+	fn set_target_ethereum_xcm_config() -> Weight;
+	fn forward_evm_call() -> Weight;
+	fn set_target_xcm_exec_bounds() -> Weight;
+	fn feed_target_xcm_exec_config() -> Weight;
+	fn set_forward_policy() -> Weight;
+	fn set_allowed_caller() -> Weight;
+	fn set_allowed_bridged_caller() -> Weight;
+	fn set_forward_rate_limit() -> Weight;
+	fn forward_from_bridge() -> Weight;
+	fn forward_to_bridge() -> Weight;
+	fn forward_batch(n: u32, ) -> Weight;
+	fn migrate_legacy_account() -> Weight;
 }
 
 /// Weight functions for `message_router`.
 pub struct SubstrateWeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeightInfo<T> {
 	// Storage: MessageRouter TargetXcmExecConfig (r:0 w:1)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:0 w:1)
 	fn set_target_xcm_exec_config() -> Weight {
-		(10_337_000 as Weight)
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+		(11_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
 	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
 	// Storage: ParachainInfo ParachainId (r:1 w:0)
 	// Storage: System Account (r:2 w:0)
 	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
@@ -75,19 +93,136 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeightInfo<T> {
 	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
 	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
 	fn forward() -> Weight {
-		(37_239_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+		(45_113_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: MessageRouter TargetEthereumXcmConfig (r:0 w:1)
+	fn set_target_ethereum_xcm_config() -> Weight {
+		(10_562_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter TargetEthereumXcmConfig (r:1 w:0)
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: System Account (r:2 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
+	fn forward_evm_call() -> Weight {
+		(49_672_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: MessageRouter TargetXcmExecBounds (r:0 w:1)
+	fn set_target_xcm_exec_bounds() -> Weight {
+		(10_786_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:0 w:1)
+	fn feed_target_xcm_exec_config() -> Weight {
+		(16_948_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: MessageRouter ForwardPolicy (r:0 w:1)
+	fn set_forward_policy() -> Weight {
+		(9_842_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: MessageRouter AllowedCallers (r:0 w:1)
+	fn set_allowed_caller() -> Weight {
+		(10_215_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter AllowedBridgedCallers (r:0 w:1)
+	fn set_allowed_bridged_caller() -> Weight {
+		(14_371_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter RateLimit (r:0 w:1)
+	fn set_forward_rate_limit() -> Weight {
+		(9_967_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: System Account (r:2 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
+	fn forward_from_bridge() -> Weight {
+		(48_926_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: BridgePangolinMessages PalletOperatingMode (r:1 w:0)
+	// Storage: BridgePangolinMessages OutboundLanes (r:1 w:1)
+	// Storage: PangolinFeeMarket AssignedRelayers (r:1 w:0)
+	// Storage: PangolinFeeMarket Orders (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BridgePangolinMessages OutboundMessages (r:0 w:1)
+	fn forward_to_bridge() -> Weight {
+		(93_514_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: System Account (r:3 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
+	fn forward_batch(n: u32, ) -> Weight {
+		(23_917_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((24_082_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn migrate_legacy_account() -> Weight {
+		(41_260_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 impl WeightInfo for () {
 	// Storage: MessageRouter TargetXcmExecConfig (r:0 w:1)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:0 w:1)
 	fn set_target_xcm_exec_config() -> Weight {
-		(10_337_000 as Weight)
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+		(11_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
 	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
 	// Storage: ParachainInfo ParachainId (r:1 w:0)
 	// Storage: System Account (r:2 w:0)
 	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
@@ -95,8 +230,119 @@ impl WeightInfo for () {
 	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
 	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
 	fn forward() -> Weight {
-		(37_239_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+		(45_113_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: MessageRouter TargetEthereumXcmConfig (r:0 w:1)
+	fn set_target_ethereum_xcm_config() -> Weight {
+		(10_562_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter TargetEthereumXcmConfig (r:1 w:0)
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: System Account (r:2 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
+	fn forward_evm_call() -> Weight {
+		(49_672_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: MessageRouter TargetXcmExecBounds (r:0 w:1)
+	fn set_target_xcm_exec_bounds() -> Weight {
+		(10_786_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:0 w:1)
+	fn feed_target_xcm_exec_config() -> Weight {
+		(16_948_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: MessageRouter ForwardPolicy (r:0 w:1)
+	fn set_forward_policy() -> Weight {
+		(9_842_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: MessageRouter AllowedCallers (r:0 w:1)
+	fn set_allowed_caller() -> Weight {
+		(10_215_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter AllowedBridgedCallers (r:0 w:1)
+	fn set_allowed_bridged_caller() -> Weight {
+		(14_371_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter RateLimit (r:0 w:1)
+	fn set_forward_rate_limit() -> Weight {
+		(9_967_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: System Account (r:2 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
+	fn forward_from_bridge() -> Weight {
+		(48_926_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: BridgePangolinMessages PalletOperatingMode (r:1 w:0)
+	// Storage: BridgePangolinMessages OutboundLanes (r:1 w:1)
+	// Storage: PangolinFeeMarket AssignedRelayers (r:1 w:0)
+	// Storage: PangolinFeeMarket Orders (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BridgePangolinMessages OutboundMessages (r:0 w:1)
+	fn forward_to_bridge() -> Weight {
+		(93_514_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: MessageRouter ForwardPolicy (r:1 w:0)
+	// Storage: MessageRouter RateLimit (r:1 w:0)
+	// Storage: MessageRouter ForwardCount (r:1 w:1)
+	// Storage: MessageRouter TargetXcmExecConfig (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecBounds (r:1 w:0)
+	// Storage: MessageRouter TargetXcmExecConfigUpdatedAt (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: System Account (r:3 w:0)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Storage: ParachainSystem RelevantMessagingState (r:1 w:0)
+	fn forward_batch(n: u32, ) -> Weight {
+		(23_917_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((24_082_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn migrate_legacy_account() -> Weight {
+		(41_260_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
\ No newline at end of file
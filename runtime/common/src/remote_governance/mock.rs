@@ -22,12 +22,14 @@ mod remote_governance {
 	pub use super::super::*;
 }
 
+// --- core ---
+use core::{cell::RefCell, time::Duration};
 // --- paritytech ---
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU32, Everything, OnInitialize},
+	traits::{ConstU32, EitherOfDiverse, Everything, OnInitialize},
 };
-use frame_system::mocking::*;
+use frame_system::{mocking::*, EnsureRoot};
 use pallet_balances::AccountData;
 use sp_core::H256;
 use sp_runtime::{
@@ -44,6 +46,7 @@ pub(crate) type Balance = u128;
 
 pub(crate) const ALICE: AccountId = AccountId32::new([0; 32]);
 pub(crate) const BOB: AccountId = AccountId32::new([1; 32]);
+pub(crate) const CHARLIE: AccountId = AccountId32::new([2; 32]);
 
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
@@ -90,6 +93,13 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_utility::Config for Test {
+	type Call = Call;
+	type Event = Event;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
 pub struct AccountIdConverter;
 impl Convert<H256, AccountId32> for AccountIdConverter {
 	fn convert(hash: H256) -> AccountId32 {
@@ -97,32 +107,105 @@ impl Convert<H256, AccountId32> for AccountIdConverter {
 	}
 }
 
-pub struct EnsureAlice;
-impl<O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>> EnsureOrigin<O>
-	for EnsureAlice
-{
-	type Success = AccountId;
+/// The blocks of this chain and the bridged chain are both produced every second.
+pub(crate) const BLOCK_TIME: u64 = 1_000;
+
+thread_local! {
+	pub static BRIDGE_FINALIZED_NUMBER: RefCell<u32> = RefCell::new(0);
+	pub static OUTBOUND_LANE_NONCES: RefCell<Option<(MessageNonce, MessageNonce)>> =
+		RefCell::new(None);
+}
+pub(crate) fn set_bridge_finalized_number(number: u32) {
+	BRIDGE_FINALIZED_NUMBER.with(|n| *n.borrow_mut() = number);
+}
+pub(crate) fn set_outbound_lane_nonces(nonces: Option<(MessageNonce, MessageNonce)>) {
+	OUTBOUND_LANE_NONCES.with(|n| *n.borrow_mut() = nonces);
+}
+pub struct BridgeFinalizedNumber;
+impl Get<u32> for BridgeFinalizedNumber {
+	fn get() -> u32 {
+		BRIDGE_FINALIZED_NUMBER.with(|n| *n.borrow())
+	}
+}
+
+pub struct OutboundLaneNonces;
+impl Get<Option<(MessageNonce, MessageNonce)>> for OutboundLaneNonces {
+	fn get() -> Option<(MessageNonce, MessageNonce)> {
+		OUTBOUND_LANE_NONCES.with(|n| *n.borrow())
+	}
+}
 
-	fn try_origin(o: O) -> Result<Self::Success, O> {
-		o.into().and_then(|o| match o {
-			RawOrigin::Signed(who) if who == ALICE => Ok(who),
-			r => Err(O::from(r)),
-		})
+pub struct MockUnixTime;
+impl UnixTime for MockUnixTime {
+	fn now() -> Duration {
+		Duration::from_millis(System::block_number() * BLOCK_TIME)
+	}
+}
+
+pub(crate) const BALANCE_CALL_DELAY: BlockNumber = 5;
+
+/// Setting the balances is timelocked, the other calls are enacted immediately.
+///
+/// The calls batched by `Utility` are timelocked by the same rules.
+pub struct MockRemoteCallDelay;
+impl RemoteCallDelay<Call, BlockNumber> for MockRemoteCallDelay {
+	fn delay_of(call: &Call) -> BlockNumber {
+		match call {
+			Call::Balances(pallet_balances::Call::set_balance { .. }) => BALANCE_CALL_DELAY,
+			_ => 0,
+		}
+	}
+
+	fn wrapped_calls(call: &Call) -> Vec<&Call> {
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls }) => calls.iter().collect(),
+			_ => Vec::new(),
+		}
+	}
+}
+
+/// Only the balances can be changed by the guardians, and the remote calls can be vetoed.
+pub struct MockSafeguardCallFilter;
+impl Contains<Call> for MockSafeguardCallFilter {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::Balances(_)
+				| Call::RemoteGovernance(remote_governance::Call::veto_remote_call { .. })
+		)
 	}
 }
 
 frame_support::parameter_types! {
 	pub const PangolinChainId: bp_runtime::ChainId = *b"pagl";
 	pub const CheckInterval: BlockNumber = 3;
+	pub const BridgedBlockTime: u64 = BLOCK_TIME;
+	pub const LaneStallThreshold: BlockNumber = 3;
+	pub const SafeguardProposalLifetime: BlockNumber = 5;
+	/// Only one scheduled call is enacted in a block.
+	pub const MaxScheduledWeight: Weight = 1;
 }
 impl Config for Test {
 	type BridgeAccountIdConverter = AccountIdConverter;
-	type BridgeFinalized = ();
+	type BridgeFinalizedNumber = BridgeFinalizedNumber;
+	type BridgedBlockTime = BridgedBlockTime;
 	type BridgedChainId = PangolinChainId;
 	type Call = Call;
 	type CheckInterval = CheckInterval;
-	type EmergencySafeguardOrigin = EnsureAlice;
+	type EmergencyEnterLag = ConstU32<2>;
+	type EmergencyLeaveLag = ConstU32<1>;
 	type Event = Event;
+	type LaneStallThreshold = LaneStallThreshold;
+	type MaxGuardians = ConstU32<3>;
+	type MaxProposalsPerGuardian = ConstU32<1>;
+	type MaxScheduledWeight = MaxScheduledWeight;
+	type Origin = Origin;
+	type OutboundLaneNonces = OutboundLaneNonces;
+	type RemoteCallDelay = MockRemoteCallDelay;
+	type SafeguardCallFilter = MockSafeguardCallFilter;
+	type SafeguardProposalLifetime = SafeguardProposalLifetime;
+	type UnixTime = MockUnixTime;
+	type VetoOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureGuardians>;
 }
 
 construct_runtime!(
@@ -133,7 +216,8 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Storage, Call, Event<T>},
-		RemoteGovernance: remote_governance::{Pallet, Storage, Call, Event<T>},
+		Utility: pallet_utility::{Pallet, Call, Event},
+		RemoteGovernance: remote_governance::{Pallet, Storage, Call, Event<T>, Origin, Config<T>},
 	}
 );
 
@@ -144,7 +228,15 @@ impl ExtBuilder {
 		let balances = vec![(ALICE, 1000)];
 		pallet_balances::GenesisConfig::<Test> { balances }.assimilate_storage(&mut t).unwrap();
 
-		t.into()
+		let mut ext = sp_io::TestExternalities::new(t);
+
+		set_bridge_finalized_number(0);
+		set_outbound_lane_nonces(None);
+
+		// The bridged chain is expected to be at the same height as this chain.
+		ext.execute_with(|| <ProgressAnchor<Test>>::put((0, 0)));
+
+		ext
 	}
 }
 impl Default for ExtBuilder {
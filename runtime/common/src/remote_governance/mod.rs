@@ -17,6 +17,28 @@
 // along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
 
 //! Prototype module for remote governance.
+//!
+//! The emergency mode is entered once the best finalized header of the bridged chain lags behind
+//! the expected progress by more than `EmergencyEnterLag` blocks, and left once the lag is no
+//! more than `EmergencyLeaveLag` blocks. The expected progress is estimated from the
+//! `ProgressAnchor` with the `BridgedBlockTime`. The anchor is only set by the governance, so a
+//! bridge finalizing slower than expected falls behind until the emergency mode is entered. The
+//! lag is checked every `CheckInterval` once the anchor is set.
+//!
+//! Optionally, it is also entered once the undelivered messages of the outbound lane are stuck for
+//! more than `LaneStallThreshold`, the lane is sampled every block.
+//!
+//! The calls from the bridged root are timelocked by the longest `RemoteCallDelay` of their
+//! category and the categories of the calls they wrap, the `VetoOrigin` can cancel them during
+//! the delay, and they are enacted at the
+//! `on_initialize` after it. The calls beyond the `MaxScheduledWeight` of a block are carried over
+//! to the next block.
+//!
+//! Under the emergency mode, the root calls are made by the M-of-N guardians instead. A guardian
+//! proposes the call with `emergency_safeguard`, it is dispatched once `GuardianThreshold`
+//! guardians approved it, and the proposal expires after `SafeguardProposalLifetime`. Outside of
+//! the emergency mode, the approved call is dispatched with the `Guardians` origin instead, which
+//! can be accepted by the `VetoOrigin`.
 
 #[cfg(test)]
 mod mock;
@@ -27,18 +49,93 @@ mod tests;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Get, UnfilteredDispatchable},
+	traits::{Contains, Get, UnfilteredDispatchable, UnixTime},
 	weights::GetDispatchInfo,
 };
-use frame_system::{ensure_signed, RawOrigin};
+use frame_system::{ensure_root, ensure_signed};
 use sp_core::H256;
-use sp_runtime::traits::{Convert, Zero};
-use sp_std::boxed::Box;
+use sp_runtime::traits::{BlakeTwo256, Convert, Hash, One, SaturatedConversion, Saturating, Zero};
+use sp_std::{boxed::Box, vec, vec::Vec};
 // --- darwinia-network ---
+use bp_messages::MessageNonce;
 use bp_runtime::{derive_account_id, ChainId, SourceAccount};
 
 type AnyCall<T> = Box<<T as Config>::Call>;
 
+/// A remote call waiting to be enacted.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct ScheduledCall<Call, BlockNumber> {
+	pub call: Call,
+	pub enact_at: BlockNumber,
+}
+
+pub type ScheduledCallOf<T> =
+	ScheduledCall<<T as Config>::Call, <T as frame_system::Config>::BlockNumber>;
+
+/// A root call proposed by a guardian under the emergency mode.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct SafeguardProposal<Call, AccountId, BlockNumber> {
+	pub call: Call,
+	/// The guardians approved the call, starting with the proposer.
+	pub approvals: Vec<AccountId>,
+	pub expire_at: BlockNumber,
+}
+
+pub type SafeguardProposalOf<T> = SafeguardProposal<
+	<T as Config>::Call,
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// Why the emergency mode was entered.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum EmergencyReason {
+	/// The best finalized header of the bridged chain lagged behind by `lag` blocks.
+	FinalityLag { lag: u32 },
+	/// The `undelivered` messages of the outbound lane were stuck.
+	LaneStalled { undelivered: MessageNonce },
+}
+
+/// The max depth of the wrapped remote calls, the deeper ones are refused.
+pub const MAX_WRAPPED_CALL_DEPTH: u32 = 4;
+
+/// The origin of the calls approved by the guardians outside of the emergency mode.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum RawOrigin {
+	/// `GuardianThreshold` guardians approved the call.
+	Guardians,
+}
+
+/// Ensure the origin is the `Guardians`.
+pub struct EnsureGuardians;
+impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>> EnsureOrigin<O> for EnsureGuardians {
+	type Success = ();
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Guardians => Ok(()),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> O {
+		O::from(RawOrigin::Guardians)
+	}
+}
+
+/// The timelock of the remote calls, by the category of the call.
+pub trait RemoteCallDelay<Call, BlockNumber> {
+	/// The delay before the call is enacted, zero enacts it immediately.
+	fn delay_of(call: &Call) -> BlockNumber;
+
+	/// The calls dispatched by the wrapper call, such as the ones of `Utility::batch`.
+	///
+	/// The longest delay of the wrapper call and the wrapped calls is used.
+	fn wrapped_calls(_call: &Call) -> Vec<&Call> {
+		Vec::new()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -49,15 +146,45 @@ pub mod pallet {
 		/// The overarching event type.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// Remote governance call or a emergency call.
-		type Call: Parameter + GetDispatchInfo + UnfilteredDispatchable<Origin = Self::Origin>;
+		/// The overarching origin type.
+		type Origin: From<RawOrigin> + From<frame_system::RawOrigin<Self::AccountId>>;
 
-		/// Origin from which the root call can be made under the emergency mode.
-		type EmergencySafeguardOrigin: EnsureOrigin<Self::Origin>;
+		/// Remote governance call or a emergency call.
+		type Call: Parameter
+			+ GetDispatchInfo
+			+ UnfilteredDispatchable<Origin = <Self as Config>::Origin>;
 
+		/// How often the bridge lag is checked outside of the emergency mode. The outbound lane
+		/// is sampled every block.
 		type CheckInterval: Get<Self::BlockNumber>;
 
-		type BridgeFinalized: Get<H256>;
+		/// The number of the best finalized header of the bridged chain.
+		type BridgeFinalizedNumber: Get<u32>;
+
+		/// Time used to estimate the expected progress of the bridged chain.
+		type UnixTime: UnixTime;
+
+		/// The expected block time of the bridged chain, in milliseconds.
+		#[pallet::constant]
+		type BridgedBlockTime: Get<u64>;
+
+		/// Enter the emergency mode once the lag of the bridged chain is above this.
+		#[pallet::constant]
+		type EmergencyEnterLag: Get<u32>;
+
+		/// Leave the emergency mode once the lag of the bridged chain is no more than this.
+		///
+		/// Lower than the `EmergencyEnterLag`, so the mode does not flap on a slow bridge.
+		#[pallet::constant]
+		type EmergencyLeaveLag: Get<u32>;
+
+		/// The `(latest_received_nonce, latest_generated_nonce)` of the watched outbound lane,
+		/// `()` to not watch any lane.
+		type OutboundLaneNonces: Get<Option<(MessageNonce, MessageNonce)>>;
+
+		/// Enter the emergency mode once the undelivered messages are stuck for more than this.
+		#[pallet::constant]
+		type LaneStallThreshold: Get<Self::BlockNumber>;
 
 		/// The bridged chain id.
 		type BridgedChainId: Get<ChainId>;
@@ -65,32 +192,101 @@ pub mod pallet {
 		/// The bridge account id converter.
 		/// `remote account` + `remote chain id` derive the new account.
 		type BridgeAccountIdConverter: Convert<H256, Self::AccountId>;
+
+		/// The delay of the remote calls before they are enacted.
+		type RemoteCallDelay: RemoteCallDelay<<Self as Config>::Call, Self::BlockNumber>;
+
+		/// The maximum weight of the scheduled calls enacted in a block. The first call is always
+		/// enacted, the rest beyond it are carried over to the next block.
+		#[pallet::constant]
+		type MaxScheduledWeight: Get<Weight>;
+
+		/// Origin from which the scheduled remote calls can be vetoed.
+		type VetoOrigin: EnsureOrigin<<Self as frame_system::Config>::Origin>;
+
+		/// The maximum number of the guardians.
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+
+		/// The maximum number of the pending safeguard proposals of a guardian.
+		#[pallet::constant]
+		type MaxProposalsPerGuardian: Get<u32>;
+
+		/// The calls which can be made by the guardians under the emergency mode.
+		type SafeguardCallFilter: Contains<<Self as Config>::Call>;
+
+		/// How long a safeguard proposal can be approved before it expires.
+		#[pallet::constant]
+		type SafeguardProposalLifetime: Get<Self::BlockNumber>;
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Bridge's GRANDPA finality has stalled for a long time, enter the emergency mode.
-		Emergency,
+		/// Bridge's GRANDPA finality or message lane has stalled for a long time, enter the
+		/// emergency mode.
+		Emergency { reason: EmergencyReason },
 		/// Recover from the emergency mode.
 		Recovery,
-		/// Remote call just enacted. \[result\]
-		RemoteCallEnacted { result: DispatchResult },
-		/// Emergency safeguard just took place. \[result\]
-		EmergencySafeguardDone { result: DispatchResult },
+		/// The progress anchor was set. \[number, moment\]
+		ProgressAnchorSet { number: u32, moment: u64 },
+		/// Remote call was scheduled, it will be enacted at `enact_at`.
+		RemoteCallScheduled { call_hash: H256, enact_at: T::BlockNumber },
+		/// Scheduled remote call was vetoed.
+		RemoteCallVetoed { call_hash: H256 },
+		/// Remote call just enacted. \[call_hash, result\]
+		RemoteCallEnacted { call_hash: H256, result: DispatchResult },
+		/// The guardians were changed, all the pending safeguard proposals were dropped.
+		GuardiansChanged { guardians: Vec<T::AccountId>, threshold: u32 },
+		/// A guardian proposed a safeguard call, it expires at `expire_at`.
+		SafeguardProposed { call_hash: H256, proposer: T::AccountId, expire_at: T::BlockNumber },
+		/// A guardian approved a safeguard call. \[call_hash, guardian, approvals\]
+		SafeguardApproved { call_hash: H256, guardian: T::AccountId, approvals: u32 },
+		/// A safeguard proposal expired before reaching the threshold.
+		SafeguardExpired { call_hash: H256 },
+		/// Emergency safeguard just took place. \[call_hash, result\]
+		EmergencySafeguardDone { call_hash: H256, result: DispatchResult },
 	}
 
+	#[pallet::origin]
+	pub type Origin = RawOrigin;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Origin MUST be `SourceRoot`.
 		RequireSourceRoot,
-		/// Only available on emergency mode.
-		EmergencyOnly,
+		/// The same call is already scheduled.
+		AlreadyScheduled,
+		/// The call is not scheduled.
+		NotScheduled,
+		/// The guardians must be distinct and no more than `MaxGuardians`, and the threshold
+		/// must be between one and the number of the guardians.
+		InvalidGuardians,
+		/// The sender is not a guardian.
+		NotGuardian,
+		/// The same call is already proposed.
+		AlreadyProposed,
+		/// The safeguard proposal does not exist or has expired.
+		ProposalNotFound,
+		/// The guardian already approved the call.
+		AlreadyApproved,
+		/// The guardian has too many pending safeguard proposals.
+		TooManyProposals,
+		/// The weight of the call is above the `max_weight`.
+		MaxWeightTooLow,
+		/// The call is not allowed by the `SafeguardCallFilter`.
+		CallNotAllowed,
+		/// The remote call is wrapped deeper than `MAX_WRAPPED_CALL_DEPTH`.
+		TooDeeplyWrapped,
 	}
 
+	/// A bridged block number and the unix time in milliseconds when it was produced.
+	///
+	/// The expected progress of the bridged chain is estimated from it, only set by the
+	/// governance.
 	#[pallet::storage]
-	#[pallet::getter(fn previous_bridge_finalized)]
-	pub type PreviousBridgeFinalized<T> = StorageValue<_, H256, ValueQuery>;
+	#[pallet::getter(fn progress_anchor)]
+	pub type ProgressAnchor<T> = StorageValue<_, (u32, u64)>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn emergency)]
@@ -100,64 +296,279 @@ pub mod pallet {
 		false
 	}
 
+	/// Why the current emergency mode was entered.
+	#[pallet::storage]
+	#[pallet::getter(fn emergency_reason)]
+	pub type EmergencyReasonOf<T> = StorageValue<_, EmergencyReason>;
+
+	/// The latest received nonce of the watched outbound lane, and since when it has not changed
+	/// with the undelivered messages.
+	#[pallet::storage]
+	#[pallet::getter(fn lane_progress)]
+	pub type LaneProgress<T: Config> = StorageValue<_, (MessageNonce, T::BlockNumber)>;
+
+	/// The remote calls waiting to be enacted, by the hash of the call.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_call)]
+	pub type ScheduledCalls<T: Config> = StorageMap<_, Identity, H256, ScheduledCallOf<T>>;
+
+	/// The hashes of the remote calls to enact at each block.
+	#[pallet::storage]
+	#[pallet::getter(fn agenda)]
+	pub type Agenda<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Vec<H256>, ValueQuery>;
+
+	/// The guardians who make the root calls under the emergency mode.
+	#[pallet::storage]
+	#[pallet::getter(fn guardians)]
+	pub type Guardians<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// The number of the approvals required to dispatch a safeguard call.
+	#[pallet::storage]
+	#[pallet::getter(fn guardian_threshold)]
+	pub type GuardianThreshold<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The pending safeguard proposals, by the hash of the call.
+	#[pallet::storage]
+	#[pallet::getter(fn safeguard_proposal)]
+	pub type SafeguardProposals<T: Config> = StorageMap<_, Identity, H256, SafeguardProposalOf<T>>;
+
+	/// The number of the pending safeguard proposals of each guardian.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_proposals)]
+	pub type PendingProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The hashes of the safeguard proposals expiring at each block.
+	#[pallet::storage]
+	#[pallet::getter(fn safeguard_expiries)]
+	pub type SafeguardExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<H256>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		pub guardians: Vec<T::AccountId>,
+		pub threshold: u32,
+	}
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { guardians: Vec::new(), threshold: 0 }
+		}
+	}
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			if self.guardians.is_empty() {
+				return;
+			}
+
+			let mut sorted = self.guardians.clone();
+
+			sorted.sort();
+			sorted.dedup();
+
+			assert!(
+				sorted.len() == self.guardians.len()
+					&& self.guardians.len() as u32 <= T::MaxGuardians::get()
+					&& self.threshold > 0
+					&& self.threshold as usize <= self.guardians.len(),
+				"Invalid genesis guardians",
+			);
+
+			<Guardians<T>>::put(&self.guardians);
+			<GuardianThreshold<T>>::put(self.threshold);
+		}
+	}
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-			// If emergency, we check the sync state each block.
-			if Self::emergency() {
-				if Self::try_sync_latest_bridge_finalized() {
-					<Emergency<T>>::kill();
-
-					Self::deposit_event(Event::Recovery);
-
-					T::DbWeight::get().reads_writes(2, 2)
-				} else {
-					T::DbWeight::get().reads(2)
-				}
-			} else if (now % T::CheckInterval::get()).is_zero() {
-				if Self::try_sync_latest_bridge_finalized() {
-					T::DbWeight::get().reads_writes(2, 1)
-				} else {
-					<Emergency<T>>::put(true);
-
-					Self::deposit_event(Event::Emergency);
-
-					T::DbWeight::get().reads_writes(2, 2)
-				}
-			} else {
-				T::DbWeight::get().reads(1)
-			}
+			Self::check_bridge(now)
+				.saturating_add(Self::enact_scheduled_calls(now))
+				.saturating_add(Self::expire_safeguard_proposals(now))
 		}
 	}
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Propose a root call under the emergency mode, approved by the proposer. Outside of the
+		/// emergency mode, the call is dispatched with the `Guardians` origin instead.
+		///
+		/// Only the calls allowed by the `SafeguardCallFilter` can be proposed.
+		///
+		/// The call is dispatched immediately if the threshold is one. Otherwise, a guardian can
+		/// have no more than `MaxProposalsPerGuardian` pending proposals.
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(dispatch_info.weight.saturating_add(10_000), dispatch_info.class)
+			(
+				dispatch_info
+					.weight
+					.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+					.saturating_add(10_000),
+				dispatch_info.class,
+			)
         })]
 		pub fn emergency_safeguard(
 			origin: OriginFor<T>,
 			call: AnyCall<T>,
 		) -> DispatchResultWithPostInfo {
-			if !Self::emergency() {
-				Err(<Error<T>>::EmergencyOnly)?;
-			}
+			let proposer = Self::ensure_guardian(origin)?;
 
-			T::EmergencySafeguardOrigin::ensure_origin(origin)?;
+			ensure!(T::SafeguardCallFilter::contains(&call), <Error<T>>::CallNotAllowed);
 
-			let res = call.dispatch_bypass_filter(RawOrigin::Root.into());
+			let call_hash = BlakeTwo256::hash_of(&call);
 
-			Self::deposit_event(Event::EmergencySafeguardDone {
-				result: res.map(|_| ()).map_err(|e| e.error),
+			ensure!(!<SafeguardProposals<T>>::contains_key(call_hash), <Error<T>>::AlreadyProposed);
+
+			let threshold = Self::guardian_threshold();
+
+			ensure!(
+				threshold <= 1
+					|| Self::pending_proposals(&proposer) < T::MaxProposalsPerGuardian::get(),
+				<Error<T>>::TooManyProposals
+			);
+
+			let expire_at = <frame_system::Pallet<T>>::block_number()
+				.saturating_add(T::SafeguardProposalLifetime::get());
+
+			Self::deposit_event(Event::SafeguardProposed {
+				call_hash,
+				proposer: proposer.clone(),
+				expire_at,
+			});
+			Self::deposit_event(Event::SafeguardApproved {
+				call_hash,
+				guardian: proposer.clone(),
+				approvals: 1,
 			});
 
-			// Sudo user does not pay a fee.
+			if threshold <= 1 {
+				Self::safeguard(call_hash, *call);
+			} else {
+				<PendingProposals<T>>::mutate(&proposer, |pending| *pending += 1);
+				<SafeguardProposals<T>>::insert(
+					call_hash,
+					SafeguardProposal { call: *call, approvals: vec![proposer], expire_at },
+				);
+				<SafeguardExpiries<T>>::append(expire_at, call_hash);
+			}
+
+			// Guardian does not pay a fee.
+			Ok(Pays::No.into())
+		}
+
+		/// Approve a pending safeguard proposal, the call is dispatched once the threshold is
+		/// reached.
+		///
+		/// The `max_weight` must cover the weight of the proposed call.
+		#[pallet::weight(
+			max_weight
+				.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+				.saturating_add(10_000)
+		)]
+		pub fn approve_emergency_safeguard(
+			origin: OriginFor<T>,
+			call_hash: H256,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let guardian = Self::ensure_guardian(origin)?;
+			let mut proposal =
+				<SafeguardProposals<T>>::get(call_hash).ok_or(<Error<T>>::ProposalNotFound)?;
+
+			ensure!(!proposal.approvals.contains(&guardian), <Error<T>>::AlreadyApproved);
+			ensure!(
+				proposal.call.get_dispatch_info().weight <= max_weight,
+				<Error<T>>::MaxWeightTooLow
+			);
+
+			proposal.approvals.push(guardian.clone());
+
+			let approvals = proposal.approvals.len() as u32;
+
+			Self::deposit_event(Event::SafeguardApproved { call_hash, guardian, approvals });
+
+			if approvals >= Self::guardian_threshold() {
+				<SafeguardProposals<T>>::remove(call_hash);
+				Self::release_proposal(&proposal);
+
+				Self::safeguard(call_hash, proposal.call);
+			} else {
+				<SafeguardProposals<T>>::insert(call_hash, proposal);
+			}
+
+			// Guardian does not pay a fee.
 			Ok(Pays::No.into())
 		}
 
+		/// Set the progress anchor, e.g. after the block time of the bridged chain drifted.
+		///
+		/// The `moment` must be when the bridged block `number` was produced, not when it was
+		/// finalized here, otherwise its lag is taken as the baseline.
+		#[pallet::weight(T::DbWeight::get().writes(1).saturating_add(10_000))]
+		pub fn set_progress_anchor(
+			origin: OriginFor<T>,
+			number: u32,
+			moment: u64,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			<ProgressAnchor<T>>::put((number, moment));
+
+			Self::deposit_event(Event::ProgressAnchorSet { number, moment });
+
+			Ok(().into())
+		}
+
+		/// Set the M-of-N guardians, e.g. by `enact_remote_call`.
+		///
+		/// All the pending safeguard proposals are dropped, there are no more than
+		/// `MaxGuardians * MaxProposalsPerGuardian` of them.
+		#[pallet::weight({
+			let max_guardians = T::MaxGuardians::get() as Weight;
+			let max_proposals =
+				max_guardians.saturating_mul(T::MaxProposalsPerGuardian::get() as Weight);
+			let cleared = max_guardians.saturating_add(max_proposals);
+
+			T::DbWeight::get()
+				.reads_writes(cleared.saturating_add(1), cleared.saturating_add(2))
+				.saturating_add(10_000)
+		})]
+		pub fn set_guardians(
+			origin: OriginFor<T>,
+			guardians: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let mut sorted = guardians.clone();
+
+			sorted.sort();
+			sorted.dedup();
+
+			ensure!(
+				sorted.len() == guardians.len()
+					&& guardians.len() as u32 <= T::MaxGuardians::get()
+					&& threshold > 0 && threshold as usize <= guardians.len(),
+				<Error<T>>::InvalidGuardians
+			);
+
+			<SafeguardProposals<T>>::drain().for_each(drop);
+			<PendingProposals<T>>::drain().for_each(drop);
+			<Guardians<T>>::put(&guardians);
+			<GuardianThreshold<T>>::put(threshold);
+
+			Self::deposit_event(Event::GuardiansChanged { guardians, threshold });
+
+			Ok(().into())
+		}
+
 		/// Handle relay message sent from the source backing pallet with relay message
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
@@ -172,30 +583,285 @@ pub mod pallet {
 
 			ensure!(source_root == sender, Error::<T>::RequireSourceRoot);
 
-			let res = call.dispatch_bypass_filter(RawOrigin::Root.into());
+			let call_hash = BlakeTwo256::hash_of(&call);
+			let delay = Self::remote_call_delay(&call, 0)?;
 
-			Self::deposit_event(Event::RemoteCallEnacted {
-				result: res.map(|_| ()).map_err(|e| e.error),
-			});
+			if delay.is_zero() {
+				Self::enact(call_hash, *call);
+			} else {
+				ensure!(
+					!<ScheduledCalls<T>>::contains_key(call_hash),
+					<Error<T>>::AlreadyScheduled
+				);
+
+				let enact_at = <frame_system::Pallet<T>>::block_number().saturating_add(delay);
+
+				<ScheduledCalls<T>>::insert(call_hash, ScheduledCall { call: *call, enact_at });
+				<Agenda<T>>::append(enact_at, call_hash);
+
+				Self::deposit_event(Event::RemoteCallScheduled { call_hash, enact_at });
+			}
 
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
 		}
+
+		/// Cancel a scheduled remote call before it is enacted.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2).saturating_add(10_000))]
+		pub fn veto_remote_call(
+			origin: OriginFor<T>,
+			call_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			T::VetoOrigin::ensure_origin(origin)?;
+
+			let ScheduledCall { enact_at, .. } =
+				<ScheduledCalls<T>>::take(call_hash).ok_or(<Error<T>>::NotScheduled)?;
+
+			<Agenda<T>>::mutate_exists(enact_at, |maybe_agenda| {
+				if let Some(agenda) = maybe_agenda {
+					agenda.retain(|h| *h != call_hash);
+
+					if agenda.is_empty() {
+						*maybe_agenda = None;
+					}
+				}
+			});
+
+			Self::deposit_event(Event::RemoteCallVetoed { call_hash });
+
+			Ok(().into())
+		}
 	}
 	impl<T: Config> Pallet<T> {
-		fn try_sync_latest_bridge_finalized() -> bool {
-			<PreviousBridgeFinalized<T>>::try_mutate(|previous_bridge_finalized| {
-				let best_finalized = T::BridgeFinalized::get();
+		fn check_bridge(now: T::BlockNumber) -> Weight {
+			// If emergency, we check the sync state each block.
+			if Self::emergency() {
+				// Always track the lane progress.
+				let lane_stalled = Self::stalled_undelivered(now).is_some();
+
+				if Self::bridge_lag() <= T::EmergencyLeaveLag::get() && !lane_stalled {
+					<Emergency<T>>::kill();
+					<EmergencyReasonOf<T>>::kill();
 
-				if *previous_bridge_finalized == best_finalized {
-					Err(())
+					Self::deposit_event(Event::Recovery);
+
+					T::DbWeight::get().reads_writes(5, 4)
 				} else {
-					*previous_bridge_finalized = best_finalized;
+					T::DbWeight::get().reads_writes(5, 2)
+				}
+			} else if let Some(reason) = Self::emergency_reason_at(now) {
+				<Emergency<T>>::put(true);
+				<EmergencyReasonOf<T>>::put(reason);
+
+				Self::deposit_event(Event::Emergency { reason });
 
-					Ok(())
+				T::DbWeight::get().reads_writes(5, 4)
+			} else {
+				T::DbWeight::get().reads_writes(5, 2)
+			}
+		}
+
+		/// Sample the outbound lane every block, and check the bridge lag every `CheckInterval`.
+		fn emergency_reason_at(now: T::BlockNumber) -> Option<EmergencyReason> {
+			// Always track the lane progress.
+			let stalled_undelivered = Self::stalled_undelivered(now);
+
+			if (now % T::CheckInterval::get()).is_zero() {
+				let lag = Self::bridge_lag();
+
+				if lag > T::EmergencyEnterLag::get() {
+					return Some(EmergencyReason::FinalityLag { lag });
 				}
+			}
+
+			stalled_undelivered.map(|undelivered| EmergencyReason::LaneStalled { undelivered })
+		}
+
+		/// The number of the undelivered messages, if they have been stuck for more than the
+		/// `LaneStallThreshold`.
+		///
+		/// Track the progress of the outbound lane, the stuck time is reset once a message is
+		/// delivered.
+		fn stalled_undelivered(now: T::BlockNumber) -> Option<MessageNonce> {
+			let (received, generated) = T::OutboundLaneNonces::get()?;
+			let undelivered = generated.saturating_sub(received);
+			let since = match Self::lane_progress() {
+				Some((previous_received, since))
+					if previous_received == received && undelivered != 0 =>
+					since,
+				_ => {
+					<LaneProgress<T>>::put((received, now));
+
+					now
+				},
+			};
+
+			if undelivered != 0 && now.saturating_sub(since) > T::LaneStallThreshold::get() {
+				Some(undelivered)
+			} else {
+				None
+			}
+		}
+
+		/// How many blocks the best finalized header lags behind the expected progress of the
+		/// bridged chain, zero if the progress anchor is not set.
+		fn bridge_lag() -> u32 {
+			let (number, moment) = match Self::progress_anchor() {
+				Some(anchor) => anchor,
+				None => return 0,
+			};
+			let now = T::UnixTime::now().as_millis().saturated_into::<u64>();
+			let elapsed = now.saturating_sub(moment) / T::BridgedBlockTime::get().max(1);
+			let expected = number.saturating_add(elapsed.saturated_into());
+
+			expected.saturating_sub(T::BridgeFinalizedNumber::get())
+		}
+
+		/// The longest delay of the remote call and the calls wrapped by it, up to
+		/// `MAX_WRAPPED_CALL_DEPTH`.
+		fn remote_call_delay(
+			call: &<T as Config>::Call,
+			depth: u32,
+		) -> Result<T::BlockNumber, DispatchError> {
+			let delay = T::RemoteCallDelay::delay_of(call);
+			let wrapped_calls = T::RemoteCallDelay::wrapped_calls(call);
+
+			if wrapped_calls.is_empty() {
+				return Ok(delay);
+			}
+
+			ensure!(depth < MAX_WRAPPED_CALL_DEPTH, <Error<T>>::TooDeeplyWrapped);
+
+			wrapped_calls.into_iter().try_fold(delay, |delay, call| {
+				Ok(delay.max(Self::remote_call_delay(call, depth.saturating_add(1))?))
 			})
-			.is_ok()
+		}
+
+		/// Enact the calls scheduled at `now` within the `MaxScheduledWeight`, carry the rest over
+		/// to the next block.
+		fn enact_scheduled_calls(now: T::BlockNumber) -> Weight {
+			let max_weight = T::MaxScheduledWeight::get();
+			let next = now.saturating_add(One::one());
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut enacted_weight: Option<Weight> = None;
+			let mut carried = Vec::new();
+
+			for call_hash in <Agenda<T>>::take(now) {
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+				let mut scheduled = match <ScheduledCalls<T>>::get(call_hash) {
+					Some(scheduled) => scheduled,
+					None => continue,
+				};
+				let call_weight = scheduled.call.get_dispatch_info().weight;
+
+				// Keep the order of the calls, carry all the rest over once one is carried.
+				let carry = !carried.is_empty()
+					|| enacted_weight
+						.map(|enacted_weight| {
+							enacted_weight.saturating_add(call_weight) > max_weight
+						})
+						.unwrap_or(false);
+
+				if carry {
+					scheduled.enact_at = next;
+
+					<ScheduledCalls<T>>::insert(call_hash, scheduled);
+
+					carried.push(call_hash);
+				} else {
+					<ScheduledCalls<T>>::remove(call_hash);
+
+					let call_weight = Self::enact(call_hash, scheduled.call);
+
+					enacted_weight =
+						Some(enacted_weight.unwrap_or_default().saturating_add(call_weight));
+					weight = weight.saturating_add(call_weight);
+				}
+			}
+
+			if !carried.is_empty() {
+				<Agenda<T>>::mutate(next, |agenda| {
+					carried.append(agenda);
+
+					*agenda = carried;
+				});
+
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+
+			weight
+		}
+
+		fn ensure_guardian(origin: OriginFor<T>) -> Result<T::AccountId, DispatchError> {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::guardians().contains(&who), <Error<T>>::NotGuardian);
+
+			Ok(who)
+		}
+
+		fn expire_safeguard_proposals(now: T::BlockNumber) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+			for call_hash in <SafeguardExpiries<T>>::take(now) {
+				weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+				// The same call might be proposed again after it was dispatched.
+				if let Some(proposal) = <SafeguardProposals<T>>::get(call_hash)
+					.filter(|proposal| proposal.expire_at == now)
+				{
+					<SafeguardProposals<T>>::remove(call_hash);
+					Self::release_proposal(&proposal);
+
+					Self::deposit_event(Event::SafeguardExpired { call_hash });
+
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+				}
+			}
+
+			weight
+		}
+
+		/// Release the pending proposal slot of the proposer.
+		fn release_proposal(proposal: &SafeguardProposalOf<T>) {
+			let proposer = match proposal.approvals.first() {
+				Some(proposer) => proposer,
+				None => return,
+			};
+
+			<PendingProposals<T>>::mutate_exists(proposer, |pending| {
+				*pending = pending.and_then(|pending| pending.checked_sub(1)).filter(|p| *p > 0);
+			});
+		}
+
+		/// Dispatch the safeguard call with `Root` under the emergency mode, otherwise with
+		/// `Guardians`.
+		fn safeguard(call_hash: H256, call: <T as Config>::Call) {
+			let origin = if Self::emergency() {
+				frame_system::RawOrigin::Root.into()
+			} else {
+				RawOrigin::Guardians.into()
+			};
+			let res = call.dispatch_bypass_filter(origin);
+
+			Self::deposit_event(Event::EmergencySafeguardDone {
+				call_hash,
+				result: res.map(|_| ()).map_err(|e| e.error),
+			});
+		}
+
+		/// Dispatch the remote call with `Root`, return the weight of it.
+		fn enact(call_hash: H256, call: <T as Config>::Call) -> Weight {
+			let weight = call.get_dispatch_info().weight;
+			let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
+
+			Self::deposit_event(Event::RemoteCallEnacted {
+				call_hash,
+				result: res.map(|_| ()).map_err(|e| e.error),
+			});
+
+			weight
 		}
 
 		pub(crate) fn derived_source_root() -> T::AccountId {
@@ -19,11 +19,11 @@
 //! Unit tests for the remote governance module.
 
 // --- paritytech ---
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::GenesisBuild};
 use sp_runtime::DispatchError::BadOrigin;
 // --- darwinia-network ---
 use super::{
-	mock::{Call, *},
+	mock::{Call, Origin, *},
 	*,
 };
 
@@ -32,6 +32,8 @@ fn emergency_safeguard_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		run_to_block(1);
 
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE], 1));
+
 		let force_balance_transfer =
 			Box::new(Call::Balances(pallet_balances::Call::<Test>::force_transfer {
 				source: ALICE,
@@ -39,12 +41,17 @@ fn emergency_safeguard_should_work() {
 				value: 11,
 			}));
 
-		assert_noop!(
-			RemoteGovernance::emergency_safeguard(
-				Origin::signed(ALICE),
-				force_balance_transfer.clone()
-			),
-			<Error<Test>>::EmergencyOnly
+		// Dispatched with the `Guardians` origin outside of the emergency mode.
+		assert_ok!(RemoteGovernance::emergency_safeguard(
+			Origin::signed(ALICE),
+			force_balance_transfer.clone()
+		));
+		System::assert_last_event(
+			super::Event::<Test>::EmergencySafeguardDone {
+				call_hash: BlakeTwo256::hash_of(&force_balance_transfer),
+				result: Err(BadOrigin),
+			}
+			.into(),
 		);
 		assert_eq!(Balances::free_balance(BOB), 0);
 
@@ -55,7 +62,7 @@ fn emergency_safeguard_should_work() {
 				Origin::signed(BOB),
 				force_balance_transfer.clone()
 			),
-			BadOrigin
+			<Error<Test>>::NotGuardian
 		);
 
 		assert_ok!(RemoteGovernance::emergency_safeguard(
@@ -96,3 +103,593 @@ fn enact_remote_call_should_work() {
 		assert_eq!(Balances::free_balance(BOB), 11);
 	});
 }
+
+fn set_bob_balance(free: Balance) -> Box<Call> {
+	Box::new(Call::Balances(pallet_balances::Call::<Test>::set_balance {
+		who: BOB,
+		new_free: free,
+		new_reserved: 0,
+	}))
+}
+
+#[test]
+fn enact_remote_call_should_be_timelocked() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		let source_root = RemoteGovernance::derived_source_root();
+		let call = set_bob_balance(100);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let enact_at = 1 + BALANCE_CALL_DELAY;
+
+		assert_ok!(RemoteGovernance::enact_remote_call(
+			Origin::signed(source_root.clone()),
+			call.clone()
+		));
+		System::assert_last_event(
+			super::Event::<Test>::RemoteCallScheduled { call_hash, enact_at }.into(),
+		);
+		assert_eq!(
+			RemoteGovernance::scheduled_call(call_hash),
+			Some(ScheduledCall { call: *call.clone(), enact_at })
+		);
+		assert_eq!(RemoteGovernance::agenda(enact_at), vec![call_hash]);
+		assert_noop!(
+			RemoteGovernance::enact_remote_call(Origin::signed(source_root), call),
+			<Error<Test>>::AlreadyScheduled
+		);
+
+		run_to_block(enact_at - 1);
+
+		assert_eq!(Balances::free_balance(BOB), 0);
+
+		run_to_block(enact_at);
+
+		assert_eq!(Balances::free_balance(BOB), 100);
+		System::assert_last_event(
+			super::Event::<Test>::RemoteCallEnacted { call_hash, result: Ok(()) }.into(),
+		);
+		assert_eq!(RemoteGovernance::scheduled_call(call_hash), None);
+		assert!(RemoteGovernance::agenda(enact_at).is_empty());
+	});
+}
+
+#[test]
+fn wrapped_remote_calls_should_be_timelocked() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		let source_root = RemoteGovernance::derived_source_root();
+		let batch =
+			|calls: Vec<Call>| Box::new(Call::Utility(pallet_utility::Call::batch { calls }));
+		let remark = || Call::System(frame_system::Call::remark { remark: vec![1] });
+
+		// Enacted immediately, none of the wrapped calls is timelocked.
+		assert_ok!(RemoteGovernance::enact_remote_call(
+			Origin::signed(source_root.clone()),
+			batch(vec![remark()])
+		));
+		assert!(RemoteGovernance::agenda(1 + BALANCE_CALL_DELAY).is_empty());
+
+		// Timelocked by the longest delay of the wrapped calls, even if nested.
+		let call = batch(vec![remark(), *batch(vec![*set_bob_balance(100)])]);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let enact_at = 1 + BALANCE_CALL_DELAY;
+
+		assert_ok!(RemoteGovernance::enact_remote_call(Origin::signed(source_root.clone()), call));
+		System::assert_last_event(
+			super::Event::<Test>::RemoteCallScheduled { call_hash, enact_at }.into(),
+		);
+		assert_eq!(Balances::free_balance(BOB), 0);
+
+		run_to_block(enact_at);
+
+		assert_eq!(Balances::free_balance(BOB), 100);
+
+		// Wrapped deeper than `MAX_WRAPPED_CALL_DEPTH`.
+		let call =
+			(0..=MAX_WRAPPED_CALL_DEPTH).fold(set_bob_balance(200), |call, _| batch(vec![*call]));
+
+		assert_noop!(
+			RemoteGovernance::enact_remote_call(Origin::signed(source_root), call),
+			<Error<Test>>::TooDeeplyWrapped
+		);
+	});
+}
+
+#[test]
+fn veto_remote_call_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		let source_root = RemoteGovernance::derived_source_root();
+		let vetoed_call = set_bob_balance(100);
+		let vetoed_call_hash = BlakeTwo256::hash_of(&vetoed_call);
+		let call = set_bob_balance(200);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let enact_at = 1 + BALANCE_CALL_DELAY;
+
+		assert_ok!(RemoteGovernance::enact_remote_call(
+			Origin::signed(source_root.clone()),
+			vetoed_call
+		));
+		assert_ok!(RemoteGovernance::enact_remote_call(Origin::signed(source_root), call));
+
+		assert_noop!(
+			RemoteGovernance::veto_remote_call(Origin::signed(ALICE), vetoed_call_hash),
+			BadOrigin
+		);
+		assert_noop!(
+			RemoteGovernance::veto_remote_call(Origin::root(), H256::zero()),
+			<Error<Test>>::NotScheduled
+		);
+
+		assert_ok!(RemoteGovernance::veto_remote_call(Origin::root(), vetoed_call_hash));
+		System::assert_last_event(
+			super::Event::<Test>::RemoteCallVetoed { call_hash: vetoed_call_hash }.into(),
+		);
+		assert_eq!(RemoteGovernance::scheduled_call(vetoed_call_hash), None);
+		assert_eq!(RemoteGovernance::agenda(enact_at), vec![call_hash]);
+
+		run_to_block(enact_at);
+
+		// Only the call which was not vetoed is enacted.
+		assert_eq!(Balances::free_balance(BOB), 200);
+	});
+}
+
+#[test]
+fn guardians_should_veto_remote_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE, BOB, CHARLIE], 2));
+
+		let source_root = RemoteGovernance::derived_source_root();
+		let call = set_bob_balance(100);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let veto =
+			Box::new(Call::RemoteGovernance(super::Call::<Test>::veto_remote_call { call_hash }));
+		let veto_hash = BlakeTwo256::hash_of(&veto);
+		let weight = veto.get_dispatch_info().weight;
+
+		assert_ok!(RemoteGovernance::enact_remote_call(Origin::signed(source_root), call));
+
+		// Not in the emergency mode, vetoed once the threshold is reached.
+		assert!(!RemoteGovernance::emergency());
+		assert_ok!(RemoteGovernance::emergency_safeguard(Origin::signed(ALICE), veto));
+		assert!(RemoteGovernance::scheduled_call(call_hash).is_some());
+		assert_ok!(RemoteGovernance::approve_emergency_safeguard(
+			Origin::signed(BOB),
+			veto_hash,
+			weight
+		));
+		System::assert_has_event(super::Event::<Test>::RemoteCallVetoed { call_hash }.into());
+		System::assert_last_event(
+			super::Event::<Test>::EmergencySafeguardDone { call_hash: veto_hash, result: Ok(()) }
+				.into(),
+		);
+		assert_eq!(RemoteGovernance::scheduled_call(call_hash), None);
+
+		run_to_block(1 + BALANCE_CALL_DELAY);
+
+		assert_eq!(Balances::free_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn scheduled_calls_should_be_carried_over() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		let source_root = RemoteGovernance::derived_source_root();
+		let calls = [set_bob_balance(100), set_bob_balance(200), set_bob_balance(300)];
+		let call_hashes = calls.iter().map(BlakeTwo256::hash_of).collect::<Vec<_>>();
+		let enact_at = 1 + BALANCE_CALL_DELAY;
+
+		for call in calls {
+			assert_ok!(RemoteGovernance::enact_remote_call(
+				Origin::signed(source_root.clone()),
+				call
+			));
+		}
+
+		run_to_block(enact_at);
+
+		// Beyond the `MaxScheduledWeight`, the rest are carried over in order.
+		assert_eq!(Balances::free_balance(BOB), 100);
+		assert_eq!(RemoteGovernance::agenda(enact_at + 1), call_hashes[1..].to_vec());
+		assert_eq!(
+			RemoteGovernance::scheduled_call(call_hashes[2]).map(|scheduled| scheduled.enact_at),
+			Some(enact_at + 1)
+		);
+
+		// The carried calls can still be vetoed.
+		assert_ok!(RemoteGovernance::veto_remote_call(Origin::root(), call_hashes[1]));
+
+		run_to_block(enact_at + 1);
+
+		assert_eq!(Balances::free_balance(BOB), 300);
+		assert!(RemoteGovernance::agenda(enact_at + 1).is_empty());
+		assert!(RemoteGovernance::agenda(enact_at + 2).is_empty());
+		assert_eq!(RemoteGovernance::scheduled_call(call_hashes[2]), None);
+	});
+}
+
+#[test]
+fn genesis_guardians_should_work() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	<super::GenesisConfig<Test> as GenesisBuild<Test>>::assimilate_storage(
+		&super::GenesisConfig { guardians: vec![ALICE, BOB], threshold: 2 },
+		&mut t,
+	)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(RemoteGovernance::guardians(), vec![ALICE, BOB]);
+		assert_eq!(RemoteGovernance::guardian_threshold(), 2);
+	});
+}
+
+#[test]
+fn set_guardians_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			RemoteGovernance::set_guardians(Origin::signed(ALICE), vec![ALICE, BOB], 2),
+			BadOrigin
+		);
+		for (guardians, threshold) in [
+			(vec![ALICE, BOB], 0),
+			(vec![ALICE, BOB], 3),
+			(vec![ALICE, ALICE], 1),
+			(vec![ALICE, BOB, CHARLIE, AccountId::new([3; 32])], 2),
+		] {
+			assert_noop!(
+				RemoteGovernance::set_guardians(Origin::root(), guardians, threshold),
+				<Error<Test>>::InvalidGuardians
+			);
+		}
+
+		// The guardians can be set by the bridged root.
+		let source_root = RemoteGovernance::derived_source_root();
+
+		assert_ok!(RemoteGovernance::enact_remote_call(
+			Origin::signed(source_root),
+			Box::new(Call::RemoteGovernance(super::Call::<Test>::set_guardians {
+				guardians: vec![ALICE, BOB],
+				threshold: 2,
+			}))
+		));
+		assert_eq!(RemoteGovernance::guardians(), vec![ALICE, BOB]);
+		assert_eq!(RemoteGovernance::guardian_threshold(), 2);
+		System::assert_has_event(
+			super::Event::<Test>::GuardiansChanged { guardians: vec![ALICE, BOB], threshold: 2 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn emergency_safeguard_should_require_threshold() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE, BOB, CHARLIE], 2));
+
+		// Enter the emergency mode.
+		run_to_block(3);
+
+		let call = set_bob_balance(100);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let weight = call.get_dispatch_info().weight;
+
+		assert_noop!(
+			RemoteGovernance::approve_emergency_safeguard(Origin::signed(BOB), call_hash, weight),
+			<Error<Test>>::ProposalNotFound
+		);
+
+		assert_ok!(RemoteGovernance::emergency_safeguard(Origin::signed(ALICE), call.clone()));
+		System::assert_last_event(
+			super::Event::<Test>::SafeguardApproved { call_hash, guardian: ALICE, approvals: 1 }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(BOB), 0);
+		assert_noop!(
+			RemoteGovernance::emergency_safeguard(Origin::signed(BOB), call),
+			<Error<Test>>::AlreadyProposed
+		);
+		assert_noop!(
+			RemoteGovernance::approve_emergency_safeguard(Origin::signed(ALICE), call_hash, weight),
+			<Error<Test>>::AlreadyApproved
+		);
+		assert_noop!(
+			RemoteGovernance::approve_emergency_safeguard(
+				Origin::signed(AccountId::new([3; 32])),
+				call_hash,
+				weight
+			),
+			<Error<Test>>::NotGuardian
+		);
+		assert_noop!(
+			RemoteGovernance::approve_emergency_safeguard(Origin::signed(BOB), call_hash, 0),
+			<Error<Test>>::MaxWeightTooLow
+		);
+
+		assert_ok!(RemoteGovernance::approve_emergency_safeguard(
+			Origin::signed(BOB),
+			call_hash,
+			weight
+		));
+		System::assert_has_event(
+			super::Event::<Test>::SafeguardApproved { call_hash, guardian: BOB, approvals: 2 }
+				.into(),
+		);
+		System::assert_last_event(
+			super::Event::<Test>::EmergencySafeguardDone { call_hash, result: Ok(()) }.into(),
+		);
+		assert_eq!(Balances::free_balance(BOB), 100);
+		assert_eq!(RemoteGovernance::safeguard_proposal(call_hash), None);
+	});
+}
+
+#[test]
+fn safeguard_proposal_should_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE, BOB], 2));
+
+		// Enter the emergency mode.
+		run_to_block(3);
+
+		let call = set_bob_balance(100);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let expire_at = 3 + SafeguardProposalLifetime::get();
+
+		assert_ok!(RemoteGovernance::emergency_safeguard(Origin::signed(ALICE), call));
+		assert_eq!(
+			RemoteGovernance::safeguard_proposal(call_hash).map(|proposal| proposal.expire_at),
+			Some(expire_at)
+		);
+
+		run_to_block(expire_at);
+
+		System::assert_last_event(super::Event::<Test>::SafeguardExpired { call_hash }.into());
+		assert_eq!(RemoteGovernance::safeguard_proposal(call_hash), None);
+		assert_noop!(
+			RemoteGovernance::approve_emergency_safeguard(
+				Origin::signed(BOB),
+				call_hash,
+				Weight::MAX
+			),
+			<Error<Test>>::ProposalNotFound
+		);
+		assert_eq!(Balances::free_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn safeguard_proposals_should_be_limited_per_guardian() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE, BOB], 2));
+
+		// Enter the emergency mode.
+		run_to_block(3);
+
+		let call = set_bob_balance(100);
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let weight = call.get_dispatch_info().weight;
+
+		assert_ok!(RemoteGovernance::emergency_safeguard(Origin::signed(ALICE), call));
+		assert_eq!(RemoteGovernance::pending_proposals(ALICE), 1);
+		assert_noop!(
+			RemoteGovernance::emergency_safeguard(Origin::signed(ALICE), set_bob_balance(200)),
+			<Error<Test>>::TooManyProposals
+		);
+
+		// The slot is released once the proposal is dispatched.
+		assert_ok!(RemoteGovernance::approve_emergency_safeguard(
+			Origin::signed(BOB),
+			call_hash,
+			weight
+		));
+		assert_eq!(RemoteGovernance::pending_proposals(ALICE), 0);
+		assert_ok!(RemoteGovernance::emergency_safeguard(
+			Origin::signed(ALICE),
+			set_bob_balance(200)
+		));
+
+		// The slot is released once the proposal expires.
+		run_to_block(3 + SafeguardProposalLifetime::get());
+
+		assert_eq!(RemoteGovernance::pending_proposals(ALICE), 0);
+		assert_ok!(RemoteGovernance::emergency_safeguard(
+			Origin::signed(ALICE),
+			set_bob_balance(300)
+		));
+
+		// All the slots are released once the guardians are changed.
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE, BOB], 2));
+		assert_eq!(RemoteGovernance::pending_proposals(ALICE), 0);
+		assert_ok!(RemoteGovernance::emergency_safeguard(
+			Origin::signed(ALICE),
+			set_bob_balance(300)
+		));
+	});
+}
+
+#[test]
+fn emergency_safeguard_should_filter_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(RemoteGovernance::set_guardians(Origin::root(), vec![ALICE], 1));
+
+		// Enter the emergency mode.
+		run_to_block(3);
+
+		// The guardians can not change themselves.
+		assert_noop!(
+			RemoteGovernance::emergency_safeguard(
+				Origin::signed(ALICE),
+				Box::new(Call::RemoteGovernance(super::Call::<Test>::set_guardians {
+					guardians: vec![BOB],
+					threshold: 1,
+				}))
+			),
+			<Error<Test>>::CallNotAllowed
+		);
+		assert_noop!(
+			RemoteGovernance::emergency_safeguard(
+				Origin::signed(ALICE),
+				Box::new(Call::System(frame_system::Call::remark { remark: vec![1] }))
+			),
+			<Error<Test>>::CallNotAllowed
+		);
+
+		assert_ok!(RemoteGovernance::emergency_safeguard(
+			Origin::signed(ALICE),
+			set_bob_balance(100)
+		));
+		assert_eq!(Balances::free_balance(BOB), 100);
+	});
+}
+
+#[test]
+fn emergency_should_follow_bridge_lag() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_bridge_finalized_number(2);
+		run_to_block(3);
+
+		// Slow but alive.
+		assert!(!RemoteGovernance::emergency());
+
+		run_to_block(6);
+
+		assert!(RemoteGovernance::emergency());
+		assert_eq!(
+			RemoteGovernance::emergency_reason(),
+			Some(EmergencyReason::FinalityLag { lag: 4 })
+		);
+		System::assert_last_event(
+			super::Event::<Test>::Emergency { reason: EmergencyReason::FinalityLag { lag: 4 } }
+				.into(),
+		);
+
+		// Below the enter lag, but still above the leave lag.
+		set_bridge_finalized_number(5);
+		run_to_block(8);
+
+		assert!(RemoteGovernance::emergency());
+
+		set_bridge_finalized_number(8);
+		run_to_block(9);
+
+		assert!(!RemoteGovernance::emergency());
+		assert_eq!(RemoteGovernance::emergency_reason(), None);
+		System::assert_last_event(super::Event::<Test>::Recovery.into());
+	});
+}
+
+#[test]
+fn progress_anchor_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		<ProgressAnchor<Test>>::kill();
+		run_to_block(6);
+
+		// Not checked until the anchor is set.
+		assert_eq!(RemoteGovernance::progress_anchor(), None);
+		assert!(!RemoteGovernance::emergency());
+
+		assert_noop!(
+			RemoteGovernance::set_progress_anchor(Origin::signed(ALICE), 200, 0),
+			BadOrigin
+		);
+		assert_ok!(RemoteGovernance::set_progress_anchor(Origin::root(), 200, 0));
+		System::assert_last_event(
+			super::Event::<Test>::ProgressAnchorSet { number: 200, moment: 0 }.into(),
+		);
+
+		// Keep finalizing the old headers.
+		set_bridge_finalized_number(150);
+		run_to_block(9);
+
+		assert!(RemoteGovernance::emergency());
+	});
+}
+
+#[test]
+fn emergency_should_catch_slow_bridge() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The bridge finalizes at 80% of the expected speed, checked at the blocks 3, 6 and 9.
+		for n in 1..=9 {
+			set_bridge_finalized_number(n as u32 * 4 / 5);
+			run_to_block(n);
+		}
+
+		// Falling behind, but not above the enter lag yet.
+		assert!(!RemoteGovernance::emergency());
+
+		for n in 10..=12 {
+			set_bridge_finalized_number(n as u32 * 4 / 5);
+			run_to_block(n);
+		}
+
+		// The lag accumulates over the intervals, the anchor is never moved.
+		assert!(RemoteGovernance::emergency());
+		assert_eq!(
+			RemoteGovernance::emergency_reason(),
+			Some(EmergencyReason::FinalityLag { lag: 3 })
+		);
+		assert_eq!(RemoteGovernance::progress_anchor(), Some((0, 0)));
+	});
+}
+
+#[test]
+fn emergency_should_follow_lane_stall() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The finality keeps up with the bridged chain in this test.
+		let run_to_block_in_sync = |n: BlockNumber| {
+			set_bridge_finalized_number(n as _);
+			run_to_block(n);
+		};
+
+		set_outbound_lane_nonces(Some((1, 1)));
+		run_to_block_in_sync(3);
+
+		assert_eq!(RemoteGovernance::lane_progress(), Some((1, 3)));
+
+		// Two messages are generated but not delivered.
+		set_outbound_lane_nonces(Some((1, 3)));
+		run_to_block_in_sync(6);
+
+		assert!(!RemoteGovernance::emergency());
+
+		// Detected once the threshold is exceeded, not at the next check.
+		run_to_block_in_sync(7);
+
+		let reason = EmergencyReason::LaneStalled { undelivered: 2 };
+
+		assert!(RemoteGovernance::emergency());
+		assert_eq!(RemoteGovernance::emergency_reason(), Some(reason));
+		System::assert_last_event(super::Event::<Test>::Emergency { reason }.into());
+
+		run_to_block_in_sync(10);
+
+		assert!(RemoteGovernance::emergency());
+
+		// One message is delivered.
+		set_outbound_lane_nonces(Some((2, 3)));
+		run_to_block_in_sync(11);
+
+		assert!(!RemoteGovernance::emergency());
+		assert_eq!(RemoteGovernance::emergency_reason(), None);
+		assert_eq!(RemoteGovernance::lane_progress(), Some((2, 11)));
+		System::assert_last_event(super::Event::<Test>::Recovery.into());
+	});
+}
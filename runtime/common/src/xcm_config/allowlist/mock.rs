@@ -0,0 +1,123 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the allowlist module.
+
+mod xcm_allowlist {
+	pub use super::super::*;
+}
+
+// --- paritytech ---
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything, GenesisBuild},
+};
+use frame_system::{mocking::*, EnsureRoot};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32,
+};
+// --- darwinia-network ---
+use super::*;
+
+pub(crate) type AccountId = AccountId32;
+pub(crate) type BlockNumber = u64;
+
+pub(crate) const ALICE: AccountId = AccountId32::new([0; 32]);
+pub(crate) const MOONBEAM_PARA_ID: u32 = 2004;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Test {
+	type AccountData = ();
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = BlockNumber;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl Config for Test {
+	type Event = Event;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = MockBlock<Test>,
+		NodeBlock = MockBlock<Test>,
+		UncheckedExtrinsic = MockUncheckedExtrinsic<Test>
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		XcmAllowlist: xcm_allowlist::{Pallet, Call, Storage, Config, Event<T>},
+	}
+);
+
+pub(crate) fn moonbeam() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)))
+}
+
+pub(crate) struct ExtBuilder;
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		<xcm_allowlist::GenesisConfig as GenesisBuild<Test>>::assimilate_storage(
+			&xcm_allowlist::GenesisConfig {
+				allowlist: vec![
+					(AllowlistKind::DescendOrigin, LocationPattern::Exact(moonbeam())),
+					(AllowlistKind::Subscription, LocationPattern::Exact(MultiLocation::parent())),
+				],
+			},
+			&mut t,
+		)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+
+		ext.execute_with(|| System::set_block_number(1));
+
+		ext
+	}
+}
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
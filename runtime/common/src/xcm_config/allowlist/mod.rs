@@ -0,0 +1,217 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Governable location allowlists for the XCM barriers.
+//!
+//! The location patterns are kept in storage, so a new partner parachain can be allowed without a
+//! runtime upgrade. A location is allowed if any pattern of the kind matches it, e.g.
+//! `LocationPattern::Within((1, Here))` allows the relay chain and all the siblings.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::{pallet_prelude::*, traits::Contains};
+use sp_std::vec::Vec;
+use xcm::latest::prelude::*;
+// --- darwinia-network ---
+use crate::xcm_config::LocationPattern;
+
+/// The barrier an allowlist applies to.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum AllowlistKind {
+	/// The locations allowed to descend the origin and pay for the execution.
+	DescendOrigin,
+	/// The locations allowed to subscribe to the XCM version changes.
+	Subscription,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which can modify the allowlists.
+		type ManagerOrigin: EnsureOrigin<Self::Origin>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A location pattern was added to the allowlist.
+		LocationAdded { kind: AllowlistKind, location: LocationPattern },
+		/// A location pattern was removed from the allowlist.
+		LocationRemoved { kind: AllowlistKind, location: LocationPattern },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location pattern is already in the allowlist.
+		AlreadyAllowed,
+		/// The location pattern is not in the allowlist.
+		NotAllowed,
+	}
+
+	/// The patterns of the allowed locations of each kind.
+	#[pallet::storage]
+	pub type Allowlist<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AllowlistKind,
+		Blake2_128Concat,
+		LocationPattern,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		pub allowlist: Vec<(AllowlistKind, LocationPattern)>,
+	}
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			Self { allowlist: Vec::new() }
+		}
+	}
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			for (kind, location) in &self.allowlist {
+				<Allowlist<T>>::insert(kind, location, ());
+			}
+		}
+	}
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add a location pattern to the allowlist.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(10_000))]
+		pub fn add_location(
+			origin: OriginFor<T>,
+			kind: AllowlistKind,
+			location: LocationPattern,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(!<Allowlist<T>>::contains_key(kind, &location), <Error<T>>::AlreadyAllowed);
+
+			<Allowlist<T>>::insert(kind, &location, ());
+
+			Self::deposit_event(Event::LocationAdded { kind, location });
+
+			Ok(().into())
+		}
+
+		/// Remove a location pattern from the allowlist.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(10_000))]
+		pub fn remove_location(
+			origin: OriginFor<T>,
+			kind: AllowlistKind,
+			location: LocationPattern,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(<Allowlist<T>>::contains_key(kind, &location), <Error<T>>::NotAllowed);
+
+			<Allowlist<T>>::remove(kind, &location);
+
+			Self::deposit_event(Event::LocationRemoved { kind, location });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// Whether any pattern of the kind matches the location.
+		pub fn is_allowed(kind: AllowlistKind, location: &MultiLocation) -> bool {
+			<Allowlist<T>>::iter_key_prefix(kind).any(|pattern| pattern.matches(location))
+		}
+
+		/// Seed the allowlist of the kind if it's empty.
+		///
+		/// Used by the runtime migrations to move the compile-time locations into the storage.
+		/// Returns the number of the inserted patterns.
+		pub fn seed(kind: AllowlistKind, locations: Vec<LocationPattern>) -> u32 {
+			if <Allowlist<T>>::iter_prefix(kind).next().is_some() {
+				return 0;
+			}
+
+			let mut count = 0;
+
+			for location in locations {
+				<Allowlist<T>>::insert(kind, location, ());
+
+				count += 1;
+			}
+
+			count
+		}
+	}
+}
+pub use pallet::*;
+
+/// Matches the locations allowed by the `DescendOrigin` allowlist, e.g. `(1, X1(Parachain(2004)))`.
+pub struct DescendOriginAllowlist<T>(PhantomData<T>);
+impl<T: Config> Contains<MultiLocation> for DescendOriginAllowlist<T> {
+	fn contains(location: &MultiLocation) -> bool {
+		<Pallet<T>>::is_allowed(AllowlistKind::DescendOrigin, location)
+	}
+}
+
+/// Matches the `AccountKey20` accounts under the locations allowed by the `DescendOrigin`
+/// allowlist, e.g. `(1, X2(Parachain(2004), AccountKey20))`.
+pub struct DescendOriginAccountsAllowlist<T>(PhantomData<T>);
+impl<T: Config> Contains<MultiLocation> for DescendOriginAccountsAllowlist<T> {
+	fn contains(location: &MultiLocation) -> bool {
+		match location {
+			MultiLocation { parents, interior: X2(chain, AccountKey20 { .. }) } =>
+				<Pallet<T>>::is_allowed(
+					AllowlistKind::DescendOrigin,
+					&MultiLocation::new(*parents, X1(chain.clone())),
+				),
+			_ => false,
+		}
+	}
+}
+
+/// Matches the locations allowed by the `Subscription` allowlist.
+pub struct SubscriptionAllowlist<T>(PhantomData<T>);
+impl<T: Config> Contains<MultiLocation> for SubscriptionAllowlist<T> {
+	fn contains(location: &MultiLocation) -> bool {
+		<Pallet<T>>::is_allowed(AllowlistKind::Subscription, location)
+	}
+}
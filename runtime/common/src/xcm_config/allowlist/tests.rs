@@ -0,0 +1,207 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the allowlist module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn astar() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(2006)))
+}
+
+fn account_of(chain: MultiLocation) -> MultiLocation {
+	chain.pushed_with_interior(AccountKey20 { network: NetworkId::Any, key: [1; 20] }).unwrap()
+}
+
+#[test]
+fn genesis_allowlist_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(DescendOriginAllowlist::<Test>::contains(&moonbeam()));
+		assert!(!DescendOriginAllowlist::<Test>::contains(&MultiLocation::parent()));
+		assert!(SubscriptionAllowlist::<Test>::contains(&MultiLocation::parent()));
+		assert!(!SubscriptionAllowlist::<Test>::contains(&moonbeam()));
+	});
+}
+
+#[test]
+fn add_location_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmAllowlist::add_location(
+				Origin::signed(ALICE),
+				AllowlistKind::DescendOrigin,
+				LocationPattern::Exact(astar())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmAllowlist::add_location(
+				Origin::root(),
+				AllowlistKind::DescendOrigin,
+				LocationPattern::Exact(moonbeam())
+			),
+			<Error<Test>>::AlreadyAllowed
+		);
+
+		assert!(!DescendOriginAllowlist::<Test>::contains(&astar()));
+		assert_ok!(XcmAllowlist::add_location(
+			Origin::root(),
+			AllowlistKind::DescendOrigin,
+			LocationPattern::Exact(astar())
+		));
+		assert!(DescendOriginAllowlist::<Test>::contains(&astar()));
+		assert!(!SubscriptionAllowlist::<Test>::contains(&astar()));
+		System::assert_last_event(
+			super::Event::<Test>::LocationAdded {
+				kind: AllowlistKind::DescendOrigin,
+				location: LocationPattern::Exact(astar()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_location_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmAllowlist::remove_location(
+				Origin::signed(ALICE),
+				AllowlistKind::DescendOrigin,
+				LocationPattern::Exact(moonbeam())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmAllowlist::remove_location(
+				Origin::root(),
+				AllowlistKind::DescendOrigin,
+				LocationPattern::Exact(astar())
+			),
+			<Error<Test>>::NotAllowed
+		);
+
+		assert_ok!(XcmAllowlist::remove_location(
+			Origin::root(),
+			AllowlistKind::DescendOrigin,
+			LocationPattern::Exact(moonbeam())
+		));
+		assert!(!DescendOriginAllowlist::<Test>::contains(&moonbeam()));
+		System::assert_last_event(
+			super::Event::<Test>::LocationRemoved {
+				kind: AllowlistKind::DescendOrigin,
+				location: LocationPattern::Exact(moonbeam()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn descend_origin_accounts_should_follow_allowlist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(DescendOriginAccountsAllowlist::<Test>::contains(&account_of(moonbeam())));
+		assert!(!DescendOriginAccountsAllowlist::<Test>::contains(&account_of(astar())));
+		// Only the `AccountKey20` accounts are matched.
+		assert!(!DescendOriginAccountsAllowlist::<Test>::contains(&moonbeam()));
+		assert!(!DescendOriginAccountsAllowlist::<Test>::contains(
+			&moonbeam()
+				.pushed_with_interior(AccountId32 { network: NetworkId::Any, id: [1; 32] })
+				.unwrap()
+		));
+
+		assert_ok!(XcmAllowlist::add_location(
+			Origin::root(),
+			AllowlistKind::DescendOrigin,
+			LocationPattern::Exact(astar())
+		));
+		assert!(DescendOriginAccountsAllowlist::<Test>::contains(&account_of(astar())));
+
+		assert_ok!(XcmAllowlist::remove_location(
+			Origin::root(),
+			AllowlistKind::DescendOrigin,
+			LocationPattern::Exact(moonbeam())
+		));
+		assert!(!DescendOriginAccountsAllowlist::<Test>::contains(&account_of(moonbeam())));
+	});
+}
+
+#[test]
+fn seed_should_only_work_on_empty_allowlist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			XcmAllowlist::seed(AllowlistKind::DescendOrigin, vec![LocationPattern::Exact(astar())]),
+			0
+		);
+		assert!(!DescendOriginAllowlist::<Test>::contains(&astar()));
+
+		assert_ok!(XcmAllowlist::remove_location(
+			Origin::root(),
+			AllowlistKind::DescendOrigin,
+			LocationPattern::Exact(moonbeam())
+		));
+		assert_eq!(
+			XcmAllowlist::seed(
+				AllowlistKind::DescendOrigin,
+				vec![LocationPattern::Exact(moonbeam()), LocationPattern::Exact(astar())]
+			),
+			2
+		);
+		assert!(DescendOriginAllowlist::<Test>::contains(&moonbeam()));
+		assert!(DescendOriginAllowlist::<Test>::contains(&astar()));
+	});
+}
+
+#[test]
+fn location_patterns_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(!SubscriptionAllowlist::<Test>::contains(&astar()));
+
+		assert_ok!(XcmAllowlist::add_location(
+			Origin::root(),
+			AllowlistKind::Subscription,
+			LocationPattern::Within(MultiLocation::parent())
+		));
+		// The relay chain and all the siblings.
+		assert!(SubscriptionAllowlist::<Test>::contains(&MultiLocation::parent()));
+		assert!(SubscriptionAllowlist::<Test>::contains(&moonbeam()));
+		assert!(SubscriptionAllowlist::<Test>::contains(&astar()));
+		assert!(!SubscriptionAllowlist::<Test>::contains(&MultiLocation::here()));
+		assert!(!SubscriptionAllowlist::<Test>::contains(&MultiLocation::grandparent()));
+
+		// Only removed by the same pattern.
+		assert_noop!(
+			XcmAllowlist::remove_location(
+				Origin::root(),
+				AllowlistKind::Subscription,
+				LocationPattern::Exact(astar())
+			),
+			<Error<Test>>::NotAllowed
+		);
+		assert_ok!(XcmAllowlist::remove_location(
+			Origin::root(),
+			AllowlistKind::Subscription,
+			LocationPattern::Within(MultiLocation::parent())
+		));
+		assert!(!SubscriptionAllowlist::<Test>::contains(&astar()));
+	});
+}
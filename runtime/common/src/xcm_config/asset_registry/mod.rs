@@ -0,0 +1,298 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Registry of the foreign assets.
+//!
+//! Maps the location of a foreign asset to the local asset id, and creates the asset in the
+//! fungibles implementation (e.g. `pallet_assets`) with the existential deposit. `AsAssetId`
+//! converts the locations for the `FungiblesAdapter` of the XCM executor.
+//!
+//! The execution fee can be paid with a foreign asset once its units per second is set, see
+//! `trader::ForeignAssetTrader`.
+
+pub mod trader;
+pub use trader::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::{borrow::Borrow, marker::PhantomData};
+// --- paritytech ---
+use frame_support::{pallet_prelude::*, traits::tokens::fungibles, PalletId};
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::vec::Vec;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::Convert;
+
+/// The metadata of a foreign asset.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct AssetMetadata<Balance, BoundedString> {
+	pub name: BoundedString,
+	pub symbol: BoundedString,
+	pub decimals: u8,
+	/// The minimum balance of an account, which is also set as the `min_balance` of the asset.
+	pub existential_deposit: Balance,
+}
+
+pub type AssetMetadataOf<T> =
+	AssetMetadata<<T as Config>::Balance, BoundedVec<u8, <T as Config>::StringLimit>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which can register the foreign assets.
+		type RegistryOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The local id of the foreign assets.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// The balance of the foreign assets.
+		type Balance: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Where the foreign assets are created.
+		type Assets: fungibles::Create<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = Self::Balance,
+		>;
+
+		/// The account derived from this id owns the foreign assets.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum length of the name and the symbol.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A foreign asset was registered.
+		AssetRegistered { asset_id: T::AssetId, location: MultiLocation },
+		/// The location of a foreign asset was updated.
+		AssetLocationUpdated {
+			asset_id: T::AssetId,
+			previous_location: MultiLocation,
+			location: MultiLocation,
+		},
+		/// The metadata of a foreign asset was updated.
+		AssetMetadataUpdated { asset_id: T::AssetId },
+		/// The units per second of a foreign asset was changed, `None` disables the payment.
+		AssetUnitsPerSecondChanged { asset_id: T::AssetId, units_per_second: Option<u128> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset id is already registered.
+		AssetIdAlreadyRegistered,
+		/// The location is already registered.
+		LocationAlreadyRegistered,
+		/// The asset is not registered.
+		AssetNotRegistered,
+		/// The name or the symbol is too long.
+		BadMetadata,
+	}
+
+	/// The location of each foreign asset.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_location)]
+	pub type AssetLocations<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, MultiLocation>;
+
+	/// The asset id of each registered location.
+	#[pallet::storage]
+	#[pallet::getter(fn location_asset)]
+	pub type LocationAssets<T: Config> = StorageMap<_, Blake2_128Concat, MultiLocation, T::AssetId>;
+
+	/// The metadata of each foreign asset.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_metadata)]
+	pub type AssetMetadatas<T: Config> =
+		StorageMap<_, Twox64Concat, T::AssetId, AssetMetadataOf<T>>;
+
+	/// The amount of a foreign asset charged for one second of the execution weight.
+	///
+	/// Only the assets listed here can pay for the XCM execution.
+	#[pallet::storage]
+	#[pallet::getter(fn units_per_second)]
+	pub type UnitsPerSecond<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, u128>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a foreign asset, and create it with the existential deposit as the minimum
+		/// balance.
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 4).saturating_add(50_000_000))]
+		pub fn register_asset(
+			origin: OriginFor<T>,
+			location: MultiLocation,
+			asset_id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			existential_deposit: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!<AssetLocations<T>>::contains_key(asset_id),
+				<Error<T>>::AssetIdAlreadyRegistered
+			);
+			ensure!(
+				!<LocationAssets<T>>::contains_key(&location),
+				<Error<T>>::LocationAlreadyRegistered
+			);
+
+			let metadata = AssetMetadata {
+				name: name.try_into().map_err(|_| <Error<T>>::BadMetadata)?,
+				symbol: symbol.try_into().map_err(|_| <Error<T>>::BadMetadata)?,
+				decimals,
+				existential_deposit,
+			};
+
+			<T::Assets as fungibles::Create<_>>::create(
+				asset_id,
+				Self::account_id(),
+				true,
+				existential_deposit,
+			)?;
+
+			<AssetLocations<T>>::insert(asset_id, &location);
+			<LocationAssets<T>>::insert(&location, asset_id);
+			<AssetMetadatas<T>>::insert(asset_id, metadata);
+
+			Self::deposit_event(Event::AssetRegistered { asset_id, location });
+
+			Ok(().into())
+		}
+
+		/// Update the location of a foreign asset, e.g. after the reserve chain migrated it.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 3).saturating_add(10_000))]
+		pub fn update_asset_location(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			location: MultiLocation,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let previous_location =
+				<AssetLocations<T>>::get(asset_id).ok_or(<Error<T>>::AssetNotRegistered)?;
+
+			ensure!(
+				!<LocationAssets<T>>::contains_key(&location),
+				<Error<T>>::LocationAlreadyRegistered
+			);
+
+			<LocationAssets<T>>::remove(&previous_location);
+			<LocationAssets<T>>::insert(&location, asset_id);
+			<AssetLocations<T>>::insert(asset_id, &location);
+
+			Self::deposit_event(Event::AssetLocationUpdated {
+				asset_id,
+				previous_location,
+				location,
+			});
+
+			Ok(().into())
+		}
+
+		/// Update the name, the symbol and the decimals of a foreign asset.
+		///
+		/// The existential deposit can not be changed.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(10_000))]
+		pub fn update_asset_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let name: BoundedVec<_, _> = name.try_into().map_err(|_| <Error<T>>::BadMetadata)?;
+			let symbol: BoundedVec<_, _> =
+				symbol.try_into().map_err(|_| <Error<T>>::BadMetadata)?;
+
+			<AssetMetadatas<T>>::try_mutate(asset_id, |maybe_metadata| {
+				let metadata = maybe_metadata.as_mut().ok_or(<Error<T>>::AssetNotRegistered)?;
+
+				metadata.name = name;
+				metadata.symbol = symbol;
+				metadata.decimals = decimals;
+
+				<Result<(), Error<T>>>::Ok(())
+			})?;
+
+			Self::deposit_event(Event::AssetMetadataUpdated { asset_id });
+
+			Ok(().into())
+		}
+
+		/// Set the units per second of a foreign asset, `None` disables paying the XCM
+		/// execution with it.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(10_000))]
+		pub fn set_asset_units_per_second(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			units_per_second: Option<u128>,
+		) -> DispatchResultWithPostInfo {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			ensure!(<AssetLocations<T>>::contains_key(asset_id), <Error<T>>::AssetNotRegistered);
+
+			<UnitsPerSecond<T>>::set(asset_id, units_per_second);
+
+			Self::deposit_event(Event::AssetUnitsPerSecondChanged { asset_id, units_per_second });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The owner of the foreign assets.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+	}
+}
+pub use pallet::*;
+
+/// Converts the registered locations to the local asset ids.
+pub struct AsAssetId<T>(PhantomData<T>);
+impl<T: Config> Convert<MultiLocation, T::AssetId> for AsAssetId<T> {
+	fn convert_ref(location: impl Borrow<MultiLocation>) -> Result<T::AssetId, ()> {
+		<LocationAssets<T>>::get(location.borrow()).ok_or(())
+	}
+
+	fn reverse_ref(asset_id: impl Borrow<T::AssetId>) -> Result<MultiLocation, ()> {
+		<AssetLocations<T>>::get(asset_id.borrow()).ok_or(())
+	}
+}
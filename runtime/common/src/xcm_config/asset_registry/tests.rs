@@ -0,0 +1,296 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the asset registry module.
+
+// --- paritytech ---
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{fungibles::Inspect, Nothing},
+	weights::constants::WEIGHT_PER_SECOND,
+};
+use sp_runtime::DispatchError::BadOrigin;
+use xcm_builder::{AccountId32Aliases, ConvertedConcreteAssetId, FungiblesAdapter};
+use xcm_executor::traits::{JustTry, TransactAsset, WeightTrader};
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+parameter_types! {
+	pub const AnyNetwork: NetworkId = NetworkId::Any;
+	pub const FeesReceiver: AccountId = AccountId::new([2; 32]);
+}
+
+type ForeignAssetsTransactor = FungiblesAdapter<
+	Assets,
+	ConvertedConcreteAssetId<u32, Balance, AsAssetId<Test>, JustTry>,
+	AccountId32Aliases<AnyNetwork, AccountId>,
+	AccountId,
+	Nothing,
+	(),
+>;
+type Trader = ForeignAssetTrader<Test, RevenueTo<ForeignAssetsTransactor, AccountId, FeesReceiver>>;
+
+fn register_dot() -> DispatchResultWithPostInfo {
+	AssetRegistry::register_asset(
+		Origin::root(),
+		dot(),
+		DOT_ID,
+		b"Polkadot".to_vec(),
+		b"DOT".to_vec(),
+		10,
+		10,
+	)
+}
+
+fn location_of(account: AccountId) -> MultiLocation {
+	MultiLocation::new(0, X1(AccountId32 { network: NetworkId::Any, id: account.into() }))
+}
+
+fn payment(location: MultiLocation, amount: u128) -> xcm_executor::Assets {
+	MultiAsset::from((location, amount)).into()
+}
+
+fn dot_balance_of(account: &AccountId) -> Balance {
+	<Assets as Inspect<AccountId>>::balance(DOT_ID, account)
+}
+
+#[test]
+fn register_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::register_asset(
+				Origin::signed(ALICE),
+				dot(),
+				DOT_ID,
+				b"Polkadot".to_vec(),
+				b"DOT".to_vec(),
+				10,
+				10,
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::register_asset(
+				Origin::root(),
+				dot(),
+				DOT_ID,
+				b"Polkadot Token".to_vec(),
+				b"DOT".to_vec(),
+				10,
+				10,
+			),
+			<Error<Test>>::BadMetadata
+		);
+
+		assert_ok!(register_dot());
+		System::assert_last_event(
+			super::Event::<Test>::AssetRegistered { asset_id: DOT_ID, location: dot() }.into(),
+		);
+
+		assert_eq!(AssetRegistry::asset_location(DOT_ID), Some(dot()));
+		assert_eq!(AssetRegistry::location_asset(dot()), Some(DOT_ID));
+		assert_eq!(
+			AssetRegistry::asset_metadata(DOT_ID),
+			Some(AssetMetadata {
+				name: b"Polkadot".to_vec().try_into().unwrap(),
+				symbol: b"DOT".to_vec().try_into().unwrap(),
+				decimals: 10,
+				existential_deposit: 10,
+			})
+		);
+		assert_eq!(<Assets as Inspect<AccountId>>::minimum_balance(DOT_ID), 10);
+	});
+}
+
+#[test]
+fn register_asset_should_reject_duplicates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register_dot());
+
+		assert_noop!(register_dot(), <Error<Test>>::AssetIdAlreadyRegistered);
+		assert_noop!(
+			AssetRegistry::register_asset(
+				Origin::root(),
+				dot(),
+				DOT_ID + 1,
+				b"Polkadot".to_vec(),
+				b"DOT".to_vec(),
+				10,
+				10,
+			),
+			<Error<Test>>::LocationAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn update_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let new_location = MultiLocation::new(1, X1(Parachain(1000)));
+
+		assert_noop!(
+			AssetRegistry::update_asset_location(Origin::root(), DOT_ID, new_location.clone()),
+			<Error<Test>>::AssetNotRegistered
+		);
+		assert_ok!(register_dot());
+		assert_noop!(
+			AssetRegistry::update_asset_location(Origin::root(), DOT_ID, dot()),
+			<Error<Test>>::LocationAlreadyRegistered
+		);
+
+		assert_ok!(AssetRegistry::update_asset_location(
+			Origin::root(),
+			DOT_ID,
+			new_location.clone()
+		));
+		assert_eq!(AssetRegistry::asset_location(DOT_ID), Some(new_location.clone()));
+		assert_eq!(AssetRegistry::location_asset(new_location), Some(DOT_ID));
+		assert_eq!(AssetRegistry::location_asset(dot()), None);
+
+		assert_ok!(AssetRegistry::update_asset_metadata(
+			Origin::root(),
+			DOT_ID,
+			b"Dot".to_vec(),
+			b"xcDOT".to_vec(),
+			12
+		));
+		let metadata = AssetRegistry::asset_metadata(DOT_ID).unwrap();
+		assert_eq!(metadata.symbol.into_inner(), b"xcDOT".to_vec());
+		assert_eq!(metadata.decimals, 12);
+		assert_eq!(metadata.existential_deposit, 10);
+	});
+}
+
+#[test]
+fn foreign_assets_transactor_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register_dot());
+
+		assert_eq!(AsAssetId::<Test>::convert_ref(dot()), Ok(DOT_ID));
+		assert_eq!(AsAssetId::<Test>::reverse_ref(DOT_ID), Ok(dot()));
+		assert_eq!(AsAssetId::<Test>::convert_ref(MultiLocation::here()), Err(()));
+
+		assert_ok!(ForeignAssetsTransactor::deposit_asset(
+			&(Parent, 100).into(),
+			&location_of(ALICE)
+		));
+		assert_eq!(<Assets as Inspect<AccountId>>::balance(DOT_ID, &ALICE), 100);
+
+		// Below the existential deposit.
+		assert!(ForeignAssetsTransactor::deposit_asset(
+			&(Parent, 1).into(),
+			&location_of([1; 32].into())
+		)
+		.is_err());
+		// Not registered.
+		assert!(ForeignAssetsTransactor::deposit_asset(
+			&(MultiLocation::new(1, X1(Parachain(1000))), 100).into(),
+			&location_of(ALICE)
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn set_asset_units_per_second_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::set_asset_units_per_second(Origin::signed(ALICE), DOT_ID, Some(1)),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::set_asset_units_per_second(Origin::root(), DOT_ID, Some(1)),
+			<Error<Test>>::AssetNotRegistered
+		);
+
+		assert_ok!(register_dot());
+		assert_ok!(AssetRegistry::set_asset_units_per_second(Origin::root(), DOT_ID, Some(1)));
+		assert_eq!(AssetRegistry::units_per_second(DOT_ID), Some(1));
+		System::assert_last_event(
+			super::Event::<Test>::AssetUnitsPerSecondChanged {
+				asset_id: DOT_ID,
+				units_per_second: Some(1),
+			}
+			.into(),
+		);
+
+		assert_ok!(AssetRegistry::set_asset_units_per_second(Origin::root(), DOT_ID, None));
+		assert_eq!(AssetRegistry::units_per_second(DOT_ID), None);
+	});
+}
+
+#[test]
+fn foreign_asset_trader_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register_dot());
+		// 1 DOT per weight.
+		assert_ok!(AssetRegistry::set_asset_units_per_second(
+			Origin::root(),
+			DOT_ID,
+			Some(WEIGHT_PER_SECOND as u128)
+		));
+
+		{
+			let mut trader = Trader::new();
+
+			assert_eq!(trader.buy_weight(1_000, payment(dot(), 1_500)), Ok(payment(dot(), 500)));
+			assert_eq!(trader.refund_weight(400), Some((Parent, 400).into()));
+			// Can not refund more than bought.
+			assert_eq!(trader.refund_weight(1_000), Some((Parent, 600).into()));
+			assert_eq!(trader.refund_weight(1_000), None);
+
+			assert_eq!(
+				trader.buy_weight(100, payment(dot(), 100)),
+				Ok(xcm_executor::Assets::new())
+			);
+		}
+
+		// The revenue is deposited when the trader is dropped.
+		assert_eq!(dot_balance_of(&FeesReceiver::get()), 100);
+	});
+}
+
+#[test]
+fn foreign_asset_trader_should_reject_unusable_payments() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(register_dot());
+
+		let mut trader = Trader::new();
+
+		// The units per second is not set.
+		assert_eq!(trader.buy_weight(1_000, payment(dot(), 1_500)), Err(XcmError::AssetNotFound));
+		// Not registered.
+		assert_eq!(
+			trader.buy_weight(1_000, payment(MultiLocation::new(1, X1(Parachain(1000))), 1_500)),
+			Err(XcmError::AssetNotFound)
+		);
+
+		assert_ok!(AssetRegistry::set_asset_units_per_second(
+			Origin::root(),
+			DOT_ID,
+			Some(WEIGHT_PER_SECOND as u128)
+		));
+
+		assert_eq!(trader.buy_weight(1_000, payment(dot(), 999)), Err(XcmError::TooExpensive));
+		assert_eq!(trader.refund_weight(1_000), None);
+
+		drop(trader);
+
+		assert_eq!(dot_balance_of(&FeesReceiver::get()), 0);
+	});
+}
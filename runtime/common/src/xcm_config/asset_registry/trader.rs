@@ -0,0 +1,147 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! The XCM trader charging the registered foreign assets.
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::{
+	log,
+	traits::Get,
+	weights::{constants::WEIGHT_PER_SECOND, Weight},
+};
+use xcm::latest::prelude::*;
+use xcm_builder::TakeRevenue;
+use xcm_executor::{
+	traits::{Convert, TransactAsset, WeightTrader},
+	Assets,
+};
+// --- darwinia-network ---
+use super::{AsAssetId, Config, UnitsPerSecond};
+
+/// Buys the execution weight with the first registered foreign asset which has the units per
+/// second configured, e.g. the same as `FixedRateOfFungible`, but the rate is governable.
+///
+/// The fees are handed to `R` when the trader is dropped.
+pub struct ForeignAssetTrader<T: Config, R: TakeRevenue> {
+	weight: Weight,
+	/// `(asset location, units per second, consumed amount)`
+	consumed: Option<(MultiLocation, u128, u128)>,
+	_phantom: PhantomData<(T, R)>,
+}
+impl<T: Config, R: TakeRevenue> ForeignAssetTrader<T, R> {
+	fn units_per_second_of(location: &MultiLocation) -> Option<u128> {
+		AsAssetId::<T>::convert_ref(location).ok().and_then(<UnitsPerSecond<T>>::get)
+	}
+}
+impl<T: Config, R: TakeRevenue> WeightTrader for ForeignAssetTrader<T, R> {
+	fn new() -> Self {
+		Self { weight: 0, consumed: None, _phantom: PhantomData }
+	}
+
+	fn buy_weight(&mut self, weight: Weight, payment: Assets) -> Result<Assets, XcmError> {
+		log::trace!(
+			target: "xcm::weight",
+			"ForeignAssetTrader::buy_weight weight: {:?}, payment: {:?}",
+			weight, payment,
+		);
+
+		let (location, units_per_second) = match &self.consumed {
+			// Keep paying with the same asset.
+			Some((location, units_per_second, _)) => (location.clone(), *units_per_second),
+			None => payment
+				.fungible_assets_iter()
+				.find_map(|asset| match asset.id {
+					Concrete(location) => Self::units_per_second_of(&location)
+						.map(|units_per_second| (location, units_per_second)),
+					_ => None,
+				})
+				.ok_or(XcmError::AssetNotFound)?,
+		};
+		let amount = units_per_second.saturating_mul(weight as u128) / (WEIGHT_PER_SECOND as u128);
+
+		if amount == 0 {
+			return Ok(payment);
+		}
+
+		let unused = payment
+			.checked_sub((location.clone(), amount).into())
+			.map_err(|_| XcmError::TooExpensive)?;
+		let consumed = self.consumed.as_ref().map(|(_, _, consumed)| *consumed).unwrap_or(0);
+
+		self.weight = self.weight.saturating_add(weight);
+		self.consumed = Some((location, units_per_second, consumed.saturating_add(amount)));
+
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
+		log::trace!(target: "xcm::weight", "ForeignAssetTrader::refund_weight weight: {:?}", weight);
+
+		let (location, units_per_second, consumed) = self.consumed.as_mut()?;
+		let weight = weight.min(self.weight);
+		let amount = (units_per_second.saturating_mul(weight as u128)
+			/ (WEIGHT_PER_SECOND as u128))
+			.min(*consumed);
+
+		self.weight -= weight;
+		*consumed -= amount;
+
+		if amount > 0 {
+			Some((location.clone(), amount).into())
+		} else {
+			None
+		}
+	}
+}
+impl<T: Config, R: TakeRevenue> Drop for ForeignAssetTrader<T, R> {
+	fn drop(&mut self) {
+		if let Some((location, _, consumed)) = self.consumed.take() {
+			if consumed > 0 {
+				R::take_revenue((location, consumed).into());
+			}
+		}
+	}
+}
+
+/// Deposits the revenue of the trader to the `Receiver`, e.g. the collator pot or the treasury.
+pub struct RevenueTo<Transactor, AccountId, Receiver>(
+	PhantomData<(Transactor, AccountId, Receiver)>,
+);
+impl<Transactor, AccountId, Receiver> TakeRevenue for RevenueTo<Transactor, AccountId, Receiver>
+where
+	Transactor: TransactAsset,
+	AccountId: Into<[u8; 32]>,
+	Receiver: Get<AccountId>,
+{
+	fn take_revenue(revenue: MultiAsset) {
+		let receiver = MultiLocation::new(
+			0,
+			X1(AccountId32 { network: NetworkId::Any, id: Receiver::get().into() }),
+		);
+
+		if let Err(e) = Transactor::deposit_asset(&revenue, &receiver) {
+			log::error!(
+				target: "xcm::weight",
+				"RevenueTo failed to deposit {:?} to {:?}, error: {:?}",
+				revenue, receiver, e,
+			);
+		}
+	}
+}
@@ -0,0 +1,265 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Index of the trapped XCM assets.
+//!
+//! `IndexedAssetTrap` wraps the asset trap of `pallet_xcm`, and keeps the origin and the assets of
+//! each trap, so they can be listed by `XcmTrappedAssetsApi`. The local accounts can reclaim their
+//! trapped assets with `claim_trapped_assets`.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::{pallet_prelude::*, weights::Weight};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Convert, Hash};
+use sp_std::{vec, vec::Vec};
+use xcm::{latest::prelude::*, IntoVersion, VersionedMultiAssets};
+use xcm_executor::{
+	traits::{ClaimAssets, DropAssets, WeightBounds},
+	Assets,
+};
+
+/// A trap of assets, the same assets of the same origin are trapped under the same hash.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct TrappedAssets {
+	/// The hash used to claim the assets, the same as the key of `pallet_xcm::AssetTraps`.
+	pub hash: H256,
+	pub origin: MultiLocation,
+	pub assets: VersionedMultiAssets,
+	/// How many times the assets were trapped.
+	pub count: u32,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::log;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Required origin for executing XCM messages.
+		type ExecuteXcmOrigin: EnsureOrigin<Self::Origin, Success = MultiLocation>;
+
+		/// Convert the beneficiary account to the location.
+		type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+		/// Used to calculate the weight required for the local execution of xcm.
+		type Weigher: WeightBounds<Self::Call>;
+
+		type XcmExecutor: ExecuteXcm<Self::Call>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The trapped assets were reclaimed to the beneficiary.
+		TrappedAssetsReclaimed { hash: H256, origin: MultiLocation, beneficiary: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No assets of the origin are trapped under the hash.
+		NotTrapped,
+		/// The version of the trapped assets is not supported.
+		BadVersion,
+		/// The message's weight could not be determined.
+		UnweighableMessage,
+		/// Failed to claim the assets.
+		XcmExecutionFailed,
+	}
+
+	/// The trapped assets of each origin.
+	/// `origin` => `hash` => `(assets, count)`
+	#[pallet::storage]
+	pub type Traps<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		Identity,
+		H256,
+		(VersionedMultiAssets, u32),
+		OptionQuery,
+	>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reclaim the assets trapped under the hash to the beneficiary.
+		///
+		/// Only the assets trapped with the caller as the origin, e.g. after a failed
+		/// `pallet_xcm::execute`, can be reclaimed.
+		#[pallet::weight(Pallet::<T>::weight_of_claim())]
+		pub fn claim_trapped_assets(
+			origin: OriginFor<T>,
+			hash: H256,
+			beneficiary: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let (assets, _) =
+				<Traps<T>>::get(&origin_location, hash).ok_or(<Error<T>>::NotTrapped)?;
+			let assets: MultiAssets = assets.try_into().map_err(|()| <Error<T>>::BadVersion)?;
+			let mut xcm =
+				Self::claim_xcm(assets, T::AccountIdToMultiLocation::convert(beneficiary.clone()));
+			let weight =
+				T::Weigher::weight(&mut xcm).map_err(|()| <Error<T>>::UnweighableMessage)?;
+
+			T::XcmExecutor::execute_xcm_in_credit(origin_location.clone(), xcm, weight, weight)
+				.ensure_complete()
+				.map_err(|error| {
+					log::error!("Failed to claim the trapped assets with {:?}", error);
+
+					<Error<T>>::XcmExecutionFailed
+				})?;
+
+			Self::deposit_event(Event::TrappedAssetsReclaimed {
+				hash,
+				origin: origin_location,
+				beneficiary,
+			});
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The trapped assets of the origin, or of all the origins if it's `None`.
+		pub fn trapped_assets(origin: Option<MultiLocation>) -> Vec<TrappedAssets> {
+			let to_trapped = |origin: MultiLocation, hash, (assets, count)| TrappedAssets {
+				hash,
+				origin,
+				assets,
+				count,
+			};
+
+			match origin {
+				Some(origin) => <Traps<T>>::iter_prefix(&origin)
+					.map(|(hash, value)| to_trapped(origin.clone(), hash, value))
+					.collect(),
+				None => <Traps<T>>::iter()
+					.map(|(origin, hash, value)| to_trapped(origin, hash, value))
+					.collect(),
+			}
+		}
+
+		/// The weight of the local execution of a claim.
+		pub fn weight_of_claim() -> Weight {
+			T::Weigher::weight(&mut Self::claim_xcm(MultiAssets::new(), MultiLocation::here()))
+				.unwrap_or(0)
+		}
+
+		/// The hash of the trapped assets, the same as `pallet_xcm`.
+		pub fn hash_of(origin: &MultiLocation, assets: &VersionedMultiAssets) -> H256 {
+			BlakeTwo256::hash_of(&(origin, assets))
+		}
+
+		fn claim_xcm(assets: MultiAssets, beneficiary: MultiLocation) -> Xcm<T::Call> {
+			let max_assets = assets.len() as u32;
+
+			Xcm(vec![
+				ClaimAsset { assets, ticket: MultiLocation::here() },
+				DepositAsset { assets: Wild(All), max_assets, beneficiary },
+			])
+		}
+	}
+}
+pub use pallet::*;
+
+/// Wraps the asset trap of `pallet_xcm`, and indexes the trapped assets.
+///
+/// Used as both the `AssetTrap` and the `AssetClaims` of the XCM executor.
+pub struct IndexedAssetTrap<T, Inner>(PhantomData<(T, Inner)>);
+impl<T: Config, Inner: DropAssets> DropAssets for IndexedAssetTrap<T, Inner> {
+	fn drop_assets(origin: &MultiLocation, assets: Assets) -> Weight {
+		// `pallet_xcm` doesn't trap nothing.
+		if assets.is_empty() {
+			return 0;
+		}
+
+		let versioned = VersionedMultiAssets::from(MultiAssets::from(assets.clone()));
+		let hash = <Pallet<T>>::hash_of(origin, &versioned);
+
+		<Traps<T>>::mutate(origin, hash, |maybe_trap| match maybe_trap {
+			Some((_, count)) => *count = count.saturating_add(1),
+			None => *maybe_trap = Some((versioned, 1)),
+		});
+
+		Inner::drop_assets(origin, assets).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+}
+impl<T: Config, Inner: ClaimAssets> ClaimAssets for IndexedAssetTrap<T, Inner> {
+	fn claim_assets(origin: &MultiLocation, ticket: &MultiLocation, assets: &MultiAssets) -> bool {
+		if !Inner::claim_assets(origin, ticket, assets) {
+			return false;
+		}
+
+		let mut versioned = VersionedMultiAssets::from(assets.clone());
+
+		// The same as `pallet_xcm`, the ticket might select the version of the trapped assets.
+		if let MultiLocation { parents: 0, interior: X1(GeneralIndex(version)) } = ticket {
+			match versioned.into_version(*version as u32) {
+				Ok(v) => versioned = v,
+				Err(()) => return true,
+			}
+		}
+
+		let hash = <Pallet<T>>::hash_of(origin, &versioned);
+
+		<Traps<T>>::mutate_exists(origin, hash, |maybe_trap| {
+			if let Some((_, count)) = maybe_trap {
+				*count = count.saturating_sub(1);
+
+				if *count == 0 {
+					*maybe_trap = None;
+				}
+			}
+		});
+
+		true
+	}
+}
+
+/// Converts the local account to `(0, X1(AccountId32))`.
+pub struct AccountIdToLocalLocation<AccountId>(PhantomData<AccountId>);
+impl<AccountId: Into<[u8; 32]>> Convert<AccountId, MultiLocation>
+	for AccountIdToLocalLocation<AccountId>
+{
+	fn convert(account_id: AccountId) -> MultiLocation {
+		MultiLocation::new(0, X1(AccountId32 { network: NetworkId::Any, id: account_id.into() }))
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to list the trapped XCM assets.
+	pub trait XcmTrappedAssetsApi {
+		/// The trapped assets of the origin, or of all the origins if it's `None`.
+		fn trapped_assets(origin: Option<MultiLocation>) -> Vec<TrappedAssets>;
+	}
+}
@@ -0,0 +1,120 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the asset trap module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+use xcm_executor::XcmExecutor;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn trap(account: AccountId, amount: Balance) -> H256 {
+	let xcm = Xcm::<Call>(vec![WithdrawAsset(native(amount).into())]);
+
+	assert_ok!(XcmExecutor::<XcmConfig>::execute_xcm_in_credit(
+		location_of(account.clone()),
+		xcm,
+		10,
+		10
+	)
+	.ensure_complete());
+
+	XcmAssetTrap::hash_of(&location_of(account), &versioned(amount))
+}
+
+fn versioned(amount: Balance) -> VersionedMultiAssets {
+	VersionedMultiAssets::from(MultiAssets::from(native(amount)))
+}
+
+#[test]
+fn trapped_assets_should_be_indexed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(XcmAssetTrap::trapped_assets(None).is_empty());
+
+		let hash = trap(ALICE, 100);
+
+		assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE - 100);
+		// The same hash as `pallet_xcm`.
+		assert_eq!(PolkadotXcm::asset_trap(hash), 1);
+		assert_eq!(
+			XcmAssetTrap::trapped_assets(Some(location_of(ALICE))),
+			vec![TrappedAssets {
+				hash,
+				origin: location_of(ALICE),
+				assets: versioned(100),
+				count: 1
+			}]
+		);
+		assert!(XcmAssetTrap::trapped_assets(Some(location_of(BOB))).is_empty());
+
+		assert_eq!(trap(ALICE, 100), hash);
+		assert_eq!(trap(ALICE, 50), XcmAssetTrap::hash_of(&location_of(ALICE), &versioned(50)));
+		assert_eq!(PolkadotXcm::asset_trap(hash), 2);
+		assert_eq!(XcmAssetTrap::trapped_assets(None).len(), 2);
+		assert_eq!(XcmAssetTrap::trapped_assets(Some(location_of(ALICE)))[0].count, 2);
+	});
+}
+
+#[test]
+fn dropping_nothing_should_not_be_indexed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(AssetTrap::drop_assets(&location_of(ALICE), Assets::new()), 0);
+		assert!(XcmAssetTrap::trapped_assets(None).is_empty());
+	});
+}
+
+#[test]
+fn claim_trapped_assets_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hash = trap(ALICE, 100);
+
+		trap(ALICE, 100);
+
+		assert_noop!(XcmAssetTrap::claim_trapped_assets(Origin::root(), hash, BOB), BadOrigin);
+		// Only the origin can claim.
+		assert_noop!(
+			XcmAssetTrap::claim_trapped_assets(Origin::signed(BOB), hash, BOB),
+			<Error<Test>>::NotTrapped
+		);
+
+		assert_ok!(XcmAssetTrap::claim_trapped_assets(Origin::signed(ALICE), hash, BOB));
+		assert_eq!(Balances::free_balance(&BOB), 100);
+		assert_eq!(PolkadotXcm::asset_trap(hash), 1);
+		assert_eq!(XcmAssetTrap::trapped_assets(Some(location_of(ALICE)))[0].count, 1);
+		System::assert_last_event(
+			super::Event::<Test>::TrappedAssetsReclaimed {
+				hash,
+				origin: location_of(ALICE),
+				beneficiary: BOB,
+			}
+			.into(),
+		);
+
+		assert_ok!(XcmAssetTrap::claim_trapped_assets(Origin::signed(ALICE), hash, BOB));
+		assert_eq!(Balances::free_balance(&BOB), 200);
+		assert_eq!(PolkadotXcm::asset_trap(hash), 0);
+		assert!(XcmAssetTrap::trapped_assets(None).is_empty());
+
+		assert_noop!(
+			XcmAssetTrap::claim_trapped_assets(Origin::signed(ALICE), hash, BOB),
+			<Error<Test>>::NotTrapped
+		);
+	});
+}
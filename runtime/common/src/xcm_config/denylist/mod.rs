@@ -0,0 +1,262 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Governable deny-list for the XCM barriers.
+//!
+//! Each rule denies a kind of instruction from the matched origins, optionally only when the
+//! instruction targets the matched destinations. `DenyByRules` is meant to be used as the `Deny`
+//! of `DenyThenTry`, and counts how many times each rule fired.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::{log, pallet_prelude::*};
+use sp_std::vec::Vec;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::ShouldExecute;
+// --- darwinia-network ---
+use crate::xcm_config::LocationPattern;
+
+pub type RuleId = u32;
+
+/// The kinds of the instructions which can be denied.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum InstructionKind {
+	WithdrawAsset,
+	ReserveAssetDeposited,
+	ReceiveTeleportedAsset,
+	ClaimAsset,
+	Transact,
+	TransferAsset,
+	TransferReserveAsset,
+	DepositAsset,
+	DepositReserveAsset,
+	InitiateReserveWithdraw,
+	InitiateTeleport,
+	ExchangeAsset,
+	SubscribeVersion,
+	HrmpChannel,
+}
+impl InstructionKind {
+	/// The kind of the instruction, and its destination if it sends assets somewhere.
+	pub fn of<Call>(instruction: &Instruction<Call>) -> Option<(Self, Option<&MultiLocation>)> {
+		let kind = match instruction {
+			WithdrawAsset(..) => (Self::WithdrawAsset, None),
+			ReserveAssetDeposited(..) => (Self::ReserveAssetDeposited, None),
+			ReceiveTeleportedAsset(..) => (Self::ReceiveTeleportedAsset, None),
+			ClaimAsset { .. } => (Self::ClaimAsset, None),
+			Transact { .. } => (Self::Transact, None),
+			TransferAsset { beneficiary, .. } => (Self::TransferAsset, Some(beneficiary)),
+			TransferReserveAsset { dest, .. } => (Self::TransferReserveAsset, Some(dest)),
+			DepositAsset { beneficiary, .. } => (Self::DepositAsset, Some(beneficiary)),
+			DepositReserveAsset { dest, .. } => (Self::DepositReserveAsset, Some(dest)),
+			InitiateReserveWithdraw { reserve, .. } =>
+				(Self::InitiateReserveWithdraw, Some(reserve)),
+			InitiateTeleport { dest, .. } => (Self::InitiateTeleport, Some(dest)),
+			ExchangeAsset { .. } => (Self::ExchangeAsset, None),
+			SubscribeVersion { .. } => (Self::SubscribeVersion, None),
+			HrmpNewChannelOpenRequest { .. }
+			| HrmpChannelAccepted { .. }
+			| HrmpChannelClosing { .. } => (Self::HrmpChannel, None),
+			_ => return None,
+		};
+
+		Some(kind)
+	}
+}
+
+/// Denies the instructions of the `instruction` kind from the `origin`.
+///
+/// The `destination` only matters for the instructions sending assets somewhere, the other kinds
+/// are only matched by `LocationPattern::Any`.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct DenyRule {
+	pub origin: LocationPattern,
+	pub instruction: InstructionKind,
+	pub destination: LocationPattern,
+}
+impl DenyRule {
+	/// Whether any instruction of the message, including the nested ones, is denied.
+	pub fn denies<Call>(&self, origin: &MultiLocation, instructions: &[Instruction<Call>]) -> bool {
+		self.origin.matches(origin) && self.denies_any(instructions)
+	}
+
+	fn denies_any<Call>(&self, instructions: &[Instruction<Call>]) -> bool {
+		instructions.iter().any(|instruction| match instruction {
+			SetErrorHandler(xcm) | SetAppendix(xcm) => self.denies_any(&xcm.0),
+			_ => match InstructionKind::of(instruction) {
+				Some((kind, destination)) if kind == self.instruction => match destination {
+					Some(destination) => self.destination.matches(destination),
+					None => self.destination == LocationPattern::Any,
+				},
+				_ => false,
+			},
+		})
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which can modify the rules.
+		type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum number of the rules, the barrier checks all of them for each message.
+		#[pallet::constant]
+		type MaxRules: Get<u32>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A deny rule was added.
+		RuleAdded { id: RuleId, rule: DenyRule },
+		/// A deny rule was removed.
+		RuleRemoved { id: RuleId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Reached the maximum number of the rules.
+		TooManyRules,
+		/// The rule does not exist.
+		RuleNotFound,
+	}
+
+	/// The deny rules.
+	#[pallet::storage]
+	pub type Rules<T> = StorageMap<_, Twox64Concat, RuleId, DenyRule>;
+
+	/// The number of the deny rules.
+	#[pallet::storage]
+	pub type RuleCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The id of the next deny rule.
+	#[pallet::storage]
+	pub type NextRuleId<T> = StorageValue<_, RuleId, ValueQuery>;
+
+	/// How many messages each rule denied.
+	///
+	/// There is at most one counter for each rule, removed together with the rule.
+	#[pallet::storage]
+	pub type RuleHits<T> = StorageMap<_, Twox64Concat, RuleId, u64, ValueQuery>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add a deny rule.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 3).saturating_add(10_000))]
+		pub fn add_rule(origin: OriginFor<T>, rule: DenyRule) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let count = <RuleCount<T>>::get();
+
+			ensure!(count < T::MaxRules::get(), <Error<T>>::TooManyRules);
+
+			let id = <NextRuleId<T>>::get();
+
+			<Rules<T>>::insert(id, &rule);
+			<RuleCount<T>>::put(count + 1);
+			<NextRuleId<T>>::put(id.saturating_add(1));
+
+			Self::deposit_event(Event::RuleAdded { id, rule });
+
+			Ok(().into())
+		}
+
+		/// Remove a deny rule and its counter.
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 3).saturating_add(10_000))]
+		pub fn remove_rule(origin: OriginFor<T>, id: RuleId) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(<Rules<T>>::contains_key(id), <Error<T>>::RuleNotFound);
+
+			<Rules<T>>::remove(id);
+			<RuleHits<T>>::remove(id);
+			<RuleCount<T>>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::RuleRemoved { id });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The rules with how many messages each of them denied.
+		pub fn rules_with_hits() -> Vec<(RuleId, DenyRule, u64)> {
+			<Rules<T>>::iter().map(|(id, rule)| (id, rule, <RuleHits<T>>::get(id))).collect()
+		}
+	}
+}
+pub use pallet::*;
+
+/// Denies the message if any of the rules matches, and counts the hit of the first matched rule.
+///
+/// The denied message pays nothing, so the counter write is registered to the block weight.
+pub struct DenyByRules<T>(PhantomData<T>);
+impl<T: Config> ShouldExecute for DenyByRules<T> {
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		message: &mut Xcm<Call>,
+		_max_weight: Weight,
+		_weight_credit: &mut Weight,
+	) -> Result<(), ()> {
+		for (id, rule) in <Rules<T>>::iter() {
+			if rule.denies(origin, &message.0) {
+				<RuleHits<T>>::mutate(id, |hits| *hits = hits.saturating_add(1));
+				<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+					T::DbWeight::get().reads_writes(1, 1),
+					DispatchClass::Mandatory,
+				);
+
+				log::trace!(
+					target: "xcm::barrier",
+					"DenyByRules rule {} denied the message from {:?}",
+					id, origin,
+				);
+
+				return Err(());
+			}
+		}
+
+		Ok(())
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to inspect the deny-list of the XCM barriers.
+	pub trait XcmDenylistApi {
+		/// The deny rules with how many messages each of them denied.
+		fn rules_with_hits() -> Vec<(RuleId, DenyRule, u64)>;
+	}
+}
@@ -0,0 +1,177 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the denylist module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok, weights::constants::RocksDbWeight};
+use sp_runtime::DispatchError::BadOrigin;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(para_id)))
+}
+
+/// Deny the reserve transfers to the relay chain, the same as `DenyReserveTransferToRelayChain`.
+fn deny_reserve_transfer_to_relay_chain() -> DenyRule {
+	DenyRule {
+		origin: LocationPattern::Any,
+		instruction: InstructionKind::DepositReserveAsset,
+		destination: LocationPattern::Exact(MultiLocation::parent()),
+	}
+}
+
+fn deposit_reserve_asset(dest: MultiLocation) -> Instruction<()> {
+	DepositReserveAsset { assets: All.into(), max_assets: 1, dest, xcm: Xcm(vec![]) }
+}
+
+fn should_execute(origin: MultiLocation, instructions: Vec<Instruction<()>>) -> bool {
+	DenyByRules::<Test>::should_execute(&origin, &mut Xcm(instructions), 10, &mut 0).is_ok()
+}
+
+#[test]
+fn location_pattern_should_work() {
+	let account = sibling(2004)
+		.pushed_with_interior(AccountKey20 { network: NetworkId::Any, key: [1; 20] })
+		.unwrap();
+
+	assert!(LocationPattern::Any.matches(&account));
+	assert!(LocationPattern::Exact(sibling(2004)).matches(&sibling(2004)));
+	assert!(!LocationPattern::Exact(sibling(2004)).matches(&account));
+	assert!(LocationPattern::Within(sibling(2004)).matches(&sibling(2004)));
+	assert!(LocationPattern::Within(sibling(2004)).matches(&account));
+	assert!(!LocationPattern::Within(sibling(2004)).matches(&sibling(2006)));
+	assert!(!LocationPattern::Within(account).matches(&sibling(2004)));
+	assert!(LocationPattern::Within(MultiLocation::parent()).matches(&sibling(2004)));
+	assert!(!LocationPattern::Within(MultiLocation::parent()).matches(&MultiLocation::here()));
+}
+
+#[test]
+fn add_and_remove_rule_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let rule = deny_reserve_transfer_to_relay_chain();
+
+		assert_noop!(XcmDenylist::add_rule(Origin::signed(ALICE), rule.clone()), BadOrigin);
+		assert_ok!(XcmDenylist::add_rule(Origin::root(), rule.clone()));
+		System::assert_last_event(
+			super::Event::<Test>::RuleAdded { id: 0, rule: rule.clone() }.into(),
+		);
+		assert_ok!(XcmDenylist::add_rule(Origin::root(), rule.clone()));
+		assert_noop!(
+			XcmDenylist::add_rule(Origin::root(), rule.clone()),
+			<Error<Test>>::TooManyRules
+		);
+
+		assert_noop!(XcmDenylist::remove_rule(Origin::signed(ALICE), 0), BadOrigin);
+		assert_noop!(XcmDenylist::remove_rule(Origin::root(), 2), <Error<Test>>::RuleNotFound);
+		assert_ok!(XcmDenylist::remove_rule(Origin::root(), 0));
+		System::assert_last_event(super::Event::<Test>::RuleRemoved { id: 0 }.into());
+		assert_eq!(<RuleCount<Test>>::get(), 1);
+
+		// The ids are not reused.
+		assert_ok!(XcmDenylist::add_rule(Origin::root(), rule.clone()));
+		assert_eq!(
+			XcmDenylist::rules_with_hits().into_iter().map(|(id, _, _)| id).collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+	});
+}
+
+#[test]
+fn deny_by_rules_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message =
+			vec![WithdrawAsset((Here, 100).into()), deposit_reserve_asset(MultiLocation::parent())];
+
+		assert!(should_execute(sibling(2004), message.clone()));
+
+		assert_ok!(XcmDenylist::add_rule(Origin::root(), deny_reserve_transfer_to_relay_chain()));
+
+		assert!(!should_execute(sibling(2004), message.clone()));
+		assert!(!should_execute(MultiLocation::parent(), message));
+		// Other destinations are fine.
+		assert!(should_execute(sibling(2004), vec![deposit_reserve_asset(sibling(2006))]));
+		// Other instructions are fine.
+		assert!(should_execute(
+			sibling(2004),
+			vec![InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: MultiLocation::parent(),
+				xcm: Xcm(vec![]),
+			}]
+		));
+		// The nested instructions are checked too.
+		assert!(!should_execute(
+			sibling(2004),
+			vec![SetAppendix(Xcm(vec![deposit_reserve_asset(MultiLocation::parent())]))]
+		));
+
+		assert_eq!(
+			XcmDenylist::rules_with_hits(),
+			vec![(0, deny_reserve_transfer_to_relay_chain(), 3)]
+		);
+	});
+}
+
+#[test]
+fn deny_by_rules_should_match_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Deny the unexpected reserve assets from the relay chain.
+		let rule = DenyRule {
+			origin: LocationPattern::Exact(MultiLocation::parent()),
+			instruction: InstructionKind::ReserveAssetDeposited,
+			destination: LocationPattern::Any,
+		};
+		let message = vec![ReserveAssetDeposited((Parent, 100).into())];
+
+		assert_ok!(XcmDenylist::add_rule(Origin::root(), rule.clone()));
+
+		assert!(!should_execute(MultiLocation::parent(), message.clone()));
+		assert!(should_execute(sibling(2004), message.clone()));
+
+		// The instructions without a destination only match `LocationPattern::Any`.
+		assert_ok!(XcmDenylist::remove_rule(Origin::root(), 0));
+		assert_ok!(XcmDenylist::add_rule(
+			Origin::root(),
+			DenyRule { destination: LocationPattern::Exact(MultiLocation::here()), ..rule }
+		));
+
+		assert!(should_execute(MultiLocation::parent(), message));
+		assert_eq!(<RuleHits<Test>>::get(0), 0);
+		assert_eq!(<RuleHits<Test>>::get(1), 0);
+	});
+}
+
+#[test]
+fn hits_should_be_charged_to_block_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message = vec![deposit_reserve_asset(MultiLocation::parent())];
+
+		assert_ok!(XcmDenylist::add_rule(Origin::root(), deny_reserve_transfer_to_relay_chain()));
+
+		let weight = System::block_weight().total();
+
+		assert!(!should_execute(sibling(2004), message));
+		assert_eq!(<RuleHits<Test>>::get(0), 1);
+		assert_eq!(
+			System::block_weight().total(),
+			weight + RocksDbWeight::get().reads_writes(1, 1)
+		);
+	});
+}
@@ -1,10 +1,42 @@
+pub mod allowlist;
+pub mod asset_registry;
+pub mod asset_trap;
+pub mod denylist;
+pub mod rate_limit;
+pub mod transact_filter;
+pub mod trust;
+
 // --- core ---
 use core::marker::PhantomData;
 // --- paritytech ---
-use frame_support::{log, weights::Weight};
+use frame_support::{log, pallet_prelude::*};
 use xcm::latest::prelude::*;
 use xcm_executor::traits::ShouldExecute;
 
+/// The pattern of the locations, e.g. the origins of a deny rule or the assets of a trust.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum LocationPattern {
+	/// Matches any location.
+	Any,
+	/// Matches the location only.
+	Exact(MultiLocation),
+	/// Matches the location and any location inside of it.
+	Within(MultiLocation),
+}
+impl LocationPattern {
+	pub fn matches(&self, location: &MultiLocation) -> bool {
+		match self {
+			LocationPattern::Any => true,
+			LocationPattern::Exact(l) => l == location,
+			LocationPattern::Within(prefix) =>
+				prefix.parents == location.parents
+					&& prefix.interior.len() <= location.interior.len()
+					&& prefix.interior.iter().zip(location.interior.iter()).all(|(a, b)| a == b),
+		}
+	}
+}
+
 //TODO: move DenyThenTry to polkadot's xcm module.
 /// Deny executing the XCM if it matches any of the Deny filter regardless of anything else.
 /// If it passes the Deny, and matches one of the Allow cases then it is let through.
@@ -0,0 +1,295 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-origin rate limit of the inbound XCM.
+//!
+//! The weight executed by each remote origin is counted in windows of blocks, and the messages
+//! beyond the budget of the origin are rejected until the next window. `RateLimited` wraps the
+//! allowing barriers, so only the messages allowed by them are counted. The local executions, e.g.
+//! `PolkadotXcm::execute`, are not limited.
+//!
+//! The usages of the past windows are pruned in `on_idle`, resuming from where the last block
+//! stopped.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::{log, pallet_prelude::*};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::prelude::*;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::ShouldExecute;
+
+/// The weight budget of each origin in a window.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct InboundRateLimit<BlockNumber> {
+	/// The length of a window in blocks.
+	pub window: BlockNumber,
+	/// The maximum weight an origin can execute in a window.
+	pub weight_budget: Weight,
+}
+
+/// The weight executed by an origin in the current window.
+#[derive(Clone, Copy, Default, Encode, Decode, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub struct Usage<BlockNumber> {
+	/// The first block of the window.
+	pub window_start: BlockNumber,
+	pub weight_used: Weight,
+	/// Whether the origin was throttled in the window, `Throttled` is only emitted once a window.
+	pub throttled: bool,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which can modify the rate limit.
+		type ManagerOrigin: EnsureOrigin<Self::Origin>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Changed the rate limit, no limitation if it's `None`.
+		RateLimitChanged { rate_limit: Option<InboundRateLimit<T::BlockNumber>> },
+		/// Changed the weight budget of an origin, which overrides the one of the rate limit.
+		OriginBudgetChanged { location: MultiLocation, weight_budget: Option<Weight> },
+		/// The origin used up its budget, its messages are rejected until the next window.
+		Throttled { origin: MultiLocation, window_start: T::BlockNumber, weight_used: Weight },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The window of the rate limit must not be zero.
+		InvalidRateLimit,
+	}
+
+	/// The rate limit of the inbound XCM. No limitation if it's `None`.
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limit)]
+	pub type RateLimit<T: Config> = StorageValue<_, InboundRateLimit<T::BlockNumber>>;
+
+	/// The weight budgets overriding the one of the rate limit, e.g. for the relay chain.
+	#[pallet::storage]
+	#[pallet::getter(fn origin_budget)]
+	pub type OriginBudgets<T> = StorageMap<_, Blake2_128Concat, MultiLocation, Weight>;
+
+	/// The weight executed by each origin in its current window.
+	#[pallet::storage]
+	#[pallet::getter(fn usage_of)]
+	pub type Usages<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, Usage<T::BlockNumber>, ValueQuery>;
+
+	/// The raw key of the last usage checked by the pruning, which resumes after it in the next
+	/// block. Starts from the head of `Usages` if it's `None`.
+	#[pallet::storage]
+	pub type PruneCursor<T> = StorageValue<_, Vec<u8>>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_usages(now, remaining_weight)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the rate limit, `None` disables it.
+		#[pallet::weight(T::DbWeight::get().writes(1).saturating_add(10_000))]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			rate_limit: Option<InboundRateLimit<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			if let Some(InboundRateLimit { window, .. }) = rate_limit {
+				ensure!(!window.is_zero(), <Error<T>>::InvalidRateLimit);
+			}
+
+			<RateLimit<T>>::set(rate_limit);
+
+			Self::deposit_event(Event::RateLimitChanged { rate_limit });
+
+			Ok(().into())
+		}
+
+		/// Set the weight budget of an origin, `None` falls back to the one of the rate limit.
+		#[pallet::weight(T::DbWeight::get().writes(1).saturating_add(10_000))]
+		pub fn set_origin_budget(
+			origin: OriginFor<T>,
+			location: MultiLocation,
+			weight_budget: Option<Weight>,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<OriginBudgets<T>>::set(&location, weight_budget);
+
+			Self::deposit_event(Event::OriginBudgetChanged { location, weight_budget });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The usage of the origin in the current window, and the budget of the origin.
+		///
+		/// Returns `None` if there is no rate limit.
+		pub fn current_usage(origin: &MultiLocation) -> Option<(Usage<T::BlockNumber>, Weight)> {
+			let InboundRateLimit { window, weight_budget } = <RateLimit<T>>::get()?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let window_start = now.saturating_sub(now % window);
+			let usage = <Usages<T>>::get(origin);
+			let usage = if usage.window_start == window_start {
+				usage
+			} else {
+				Usage { window_start, weight_used: 0, throttled: false }
+			};
+
+			Some((usage, <OriginBudgets<T>>::get(origin).unwrap_or(weight_budget)))
+		}
+
+		/// Remove the usages of the past windows, all of them if there is no rate limit.
+		///
+		/// Stops once the `remaining_weight` is used up, the next block resumes from the
+		/// `PruneCursor`.
+		pub(super) fn prune_usages(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			let mut weight = db_weight.reads_writes(2, 1);
+
+			if weight > remaining_weight {
+				return 0;
+			}
+
+			let window_start = <RateLimit<T>>::get()
+				.map(|InboundRateLimit { window, .. }| now.saturating_sub(now % window));
+			let mut cursor = <PruneCursor<T>>::get();
+			let mut usages = match &cursor {
+				Some(key) => <Usages<T>>::iter_from(key.clone()),
+				None => <Usages<T>>::iter(),
+			};
+			let mut stale = Vec::new();
+
+			loop {
+				let next_weight = weight.saturating_add(db_weight.reads_writes(1, 1));
+
+				if next_weight > remaining_weight {
+					break;
+				}
+
+				let (origin, usage) = match usages.next() {
+					Some(usage) => usage,
+					None => {
+						cursor = None;
+
+						break;
+					},
+				};
+
+				weight = next_weight;
+				cursor = Some(usages.last_raw_key().to_vec());
+
+				if window_start.map_or(true, |window_start| usage.window_start < window_start) {
+					stale.push(origin);
+				}
+			}
+
+			stale.iter().for_each(<Usages<T>>::remove);
+			<PruneCursor<T>>::set(cursor);
+
+			weight
+		}
+
+		/// Note that the origin is throttled, emit `Throttled` if it's the first time in the
+		/// window.
+		pub(super) fn note_throttled(origin: &MultiLocation, mut usage: Usage<T::BlockNumber>) {
+			if usage.throttled {
+				return;
+			}
+
+			usage.throttled = true;
+
+			<Usages<T>>::insert(origin, usage);
+
+			Self::deposit_event(Event::Throttled {
+				origin: origin.clone(),
+				window_start: usage.window_start,
+				weight_used: usage.weight_used,
+			});
+		}
+	}
+}
+pub use pallet::*;
+
+/// Rejects the messages beyond the budget of the remote origin, then tries the `Allow` barriers.
+/// The weight of the allowed messages is counted.
+///
+/// The local origins, whose `parents` is zero, only try the `Allow` barriers.
+pub struct RateLimited<T, Allow>(PhantomData<(T, Allow)>);
+impl<T: Config, Allow: ShouldExecute> ShouldExecute for RateLimited<T, Allow> {
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		message: &mut Xcm<Call>,
+		max_weight: Weight,
+		weight_credit: &mut Weight,
+	) -> Result<(), ()> {
+		if origin.parents == 0 {
+			return Allow::should_execute(origin, message, max_weight, weight_credit);
+		}
+
+		let (mut usage, weight_budget) = match <Pallet<T>>::current_usage(origin) {
+			Some(usage) => usage,
+			None => return Allow::should_execute(origin, message, max_weight, weight_credit),
+		};
+		let weight_used = usage.weight_used.saturating_add(max_weight);
+
+		if weight_used > weight_budget {
+			log::trace!(
+				target: "xcm::barrier",
+				"RateLimited throttled the message from {:?}, weight used: {}, budget: {}",
+				origin, usage.weight_used, weight_budget,
+			);
+
+			<Pallet<T>>::note_throttled(origin, usage);
+
+			return Err(());
+		}
+
+		Allow::should_execute(origin, message, max_weight, weight_credit)?;
+
+		usage.weight_used = weight_used;
+
+		<Usages<T>>::insert(origin, usage);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,221 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the rate limit module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::constants::RocksDbWeight};
+use sp_runtime::DispatchError::BadOrigin;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn check(origin: MultiLocation, weight: Weight) -> Result<(), ()> {
+	Barrier::should_execute(&origin, &mut Xcm::<()>(vec![]), weight, &mut 0)
+}
+
+fn set_rate_limit() {
+	assert_ok!(XcmRateLimit::set_rate_limit(
+		Origin::root(),
+		Some(InboundRateLimit { window: 10, weight_budget: 100 })
+	));
+}
+
+#[test]
+fn set_rate_limit_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let rate_limit = InboundRateLimit { window: 10, weight_budget: 100 };
+
+		assert_noop!(
+			XcmRateLimit::set_rate_limit(Origin::signed(ALICE), Some(rate_limit)),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmRateLimit::set_rate_limit(
+				Origin::root(),
+				Some(InboundRateLimit { window: 0, weight_budget: 100 })
+			),
+			<Error<Test>>::InvalidRateLimit
+		);
+
+		assert_ok!(XcmRateLimit::set_rate_limit(Origin::root(), Some(rate_limit)));
+		assert_eq!(XcmRateLimit::rate_limit(), Some(rate_limit));
+		System::assert_last_event(
+			super::Event::<Test>::RateLimitChanged { rate_limit: Some(rate_limit) }.into(),
+		);
+
+		assert_ok!(XcmRateLimit::set_rate_limit(Origin::root(), None));
+		assert_eq!(XcmRateLimit::rate_limit(), None);
+	});
+}
+
+#[test]
+fn no_rate_limit_should_only_check_the_inner_barrier() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(check(moonbeam(), Weight::MAX), Ok(()));
+		assert_eq!(
+			Barrier::should_execute(&moonbeam(), &mut Xcm::<()>(vec![ClearOrigin]), 1, &mut 0),
+			Err(())
+		);
+		assert_eq!(XcmRateLimit::usage_of(moonbeam()), Default::default());
+	});
+}
+
+#[test]
+fn rate_limited_should_throttle_each_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rate_limit();
+
+		assert_eq!(check(moonbeam(), 60), Ok(()));
+		assert_eq!(check(moonbeam(), 40), Ok(()));
+		assert_eq!(check(moonbeam(), 1), Err(()));
+		System::assert_last_event(
+			super::Event::<Test>::Throttled {
+				origin: moonbeam(),
+				window_start: 0,
+				weight_used: 100,
+			}
+			.into(),
+		);
+
+		let events = System::events().len();
+
+		// Only emitted once a window.
+		assert_eq!(check(moonbeam(), 1), Err(()));
+		assert_eq!(System::events().len(), events);
+
+		// The other origins are not affected.
+		assert_eq!(check(astar(), 100), Ok(()));
+
+		// Reset in the next window.
+		System::set_block_number(10);
+
+		assert_eq!(check(moonbeam(), 100), Ok(()));
+		assert_eq!(
+			XcmRateLimit::usage_of(moonbeam()),
+			Usage { window_start: 10, weight_used: 100, throttled: false }
+		);
+	});
+}
+
+#[test]
+fn rate_limited_should_only_count_the_allowed_messages() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rate_limit();
+
+		assert_eq!(
+			Barrier::should_execute(&moonbeam(), &mut Xcm::<()>(vec![ClearOrigin]), 100, &mut 0),
+			Err(())
+		);
+		assert_eq!(check(moonbeam(), 100), Ok(()));
+	});
+}
+
+#[test]
+fn origin_budget_should_override_the_rate_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rate_limit();
+
+		assert_noop!(
+			XcmRateLimit::set_origin_budget(Origin::signed(ALICE), moonbeam(), Some(200)),
+			BadOrigin
+		);
+		assert_ok!(XcmRateLimit::set_origin_budget(Origin::root(), moonbeam(), Some(200)));
+		System::assert_last_event(
+			super::Event::<Test>::OriginBudgetChanged {
+				location: moonbeam(),
+				weight_budget: Some(200),
+			}
+			.into(),
+		);
+
+		assert_eq!(check(moonbeam(), 200), Ok(()));
+		assert_eq!(check(moonbeam(), 1), Err(()));
+		assert_eq!(check(astar(), 101), Err(()));
+
+		assert_ok!(XcmRateLimit::set_origin_budget(Origin::root(), moonbeam(), None));
+		assert_eq!(XcmRateLimit::origin_budget(moonbeam()), None);
+	});
+}
+
+#[test]
+fn rate_limited_should_skip_the_local_origins() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rate_limit();
+
+		assert_eq!(check(local_account(), 100), Ok(()));
+		assert_eq!(check(local_account(), 100), Ok(()));
+		assert!(!<Usages<Test>>::contains_key(local_account()));
+		// Still checked by the inner barrier.
+		assert_eq!(
+			Barrier::should_execute(&local_account(), &mut Xcm::<()>(vec![ClearOrigin]), 1, &mut 0),
+			Err(())
+		);
+	});
+}
+
+#[test]
+fn stale_usages_should_be_pruned() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rate_limit();
+
+		assert_eq!(check(moonbeam(), 10), Ok(()));
+		System::set_block_number(10);
+		assert_eq!(check(astar(), 10), Ok(()));
+
+		XcmRateLimit::on_idle(10, Weight::MAX);
+		assert!(!<Usages<Test>>::contains_key(moonbeam()));
+		assert!(<Usages<Test>>::contains_key(astar()));
+
+		// All of them are stale without the rate limit.
+		assert_ok!(XcmRateLimit::set_rate_limit(Origin::root(), None));
+		XcmRateLimit::on_idle(10, Weight::MAX);
+		assert_eq!(<Usages<Test>>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn stale_usages_pruning_should_resume_from_cursor() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_rate_limit();
+
+		for origin in [moonbeam(), astar(), MultiLocation::parent()] {
+			assert_eq!(check(origin, 10), Ok(()));
+		}
+
+		// Only the relay chain is stale, wherever it is in the `Usages`.
+		System::set_block_number(10);
+		assert_eq!(check(moonbeam(), 10), Ok(()));
+		assert_eq!(check(astar(), 10), Ok(()));
+
+		// Enough to check one usage a block.
+		let remaining_weight = RocksDbWeight::get()
+			.reads_writes(2, 1)
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1));
+
+		for _ in 0..3 {
+			XcmRateLimit::on_idle(10, remaining_weight);
+			assert!(<PruneCursor<Test>>::get().is_some());
+		}
+		assert!(!<Usages<Test>>::contains_key(MultiLocation::parent()));
+		assert_eq!(<Usages<Test>>::iter().count(), 2);
+
+		// Starts from the head again once the end is reached.
+		XcmRateLimit::on_idle(10, remaining_weight);
+		assert!(<PruneCursor<Test>>::get().is_none());
+	});
+}
@@ -0,0 +1,109 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the transact filter module.
+
+// --- paritytech ---
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything},
+};
+use frame_system::mocking::*;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+// --- darwinia-network ---
+use super::*;
+
+pub(crate) type AccountId = u64;
+pub(crate) type BlockNumber = u64;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+impl frame_system::Config for Test {
+	type AccountData = ();
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = BlockHashCount;
+	type BlockLength = ();
+	type BlockNumber = BlockNumber;
+	type BlockWeights = ();
+	type Call = Call;
+	type DbWeight = ();
+	type Event = Event;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type Origin = Origin;
+	type PalletInfo = PalletInfo;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_utility::Config for Test {
+	type Call = Call;
+	type Event = Event;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = MockBlock<Test>,
+		NodeBlock = MockBlock<Test>,
+		UncheckedExtrinsic = MockUncheckedExtrinsic<Test>
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Utility: pallet_utility::{Pallet, Call, Event},
+	}
+);
+
+/// Anyone can remark, only the superuser can set the storage, and nobody can set the code.
+///
+/// The calls can be batched, but can't be dispatched as another origin.
+pub(crate) struct MockTransactFilter;
+impl TransactFilter<Call> for MockTransactFilter {
+	fn allows(origin_kind: OriginKind, call: &Call) -> bool {
+		match call {
+			Call::System(frame_system::Call::remark { .. })
+			| Call::Utility(pallet_utility::Call::batch { .. }) => true,
+			Call::System(frame_system::Call::set_storage { .. }) =>
+				origin_kind == OriginKind::Superuser,
+			_ => false,
+		}
+	}
+
+	fn wrapped_calls(call: &Call) -> Vec<&Call> {
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls }) => calls.iter().collect(),
+			_ => Vec::new(),
+		}
+	}
+}
+
+pub(crate) type Barrier = DenyUnsafeTransact<Call, MockTransactFilter>;
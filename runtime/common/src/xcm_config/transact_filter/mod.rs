@@ -0,0 +1,122 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Filter of the calls dispatched by the XCM `Transact`.
+//!
+//! The XCM origins converted by `ParentAsSuperuser` are `Root`, which bypasses the
+//! `BaseCallFilter`, so the calls are checked by a barrier before the execution. The calls wrapped
+//! by the others, such as `Utility::batch` or `Proxy::proxy`, are checked too. Meant to be used
+//! as the `Deny` of `DenyThenTry`.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::{log, pallet_prelude::*};
+use sp_std::prelude::*;
+use xcm::{latest::prelude::*, DoubleEncoded};
+use xcm_executor::traits::ShouldExecute;
+
+/// The max depth of the wrapped calls, the deeper ones are denied.
+pub const MAX_WRAPPED_CALL_DEPTH: u32 = 4;
+
+/// Decides which calls the XCM `Transact` can dispatch, by the kind of the dispatch origin.
+pub trait TransactFilter<Call> {
+	/// Whether the origin of the kind is allowed to dispatch the call.
+	///
+	/// The wrapper calls whose wrapped calls can't be listed by `wrapped_calls`, such as the
+	/// opaque ones of `Multisig::as_multi`, should be denied here.
+	fn allows(origin_kind: OriginKind, call: &Call) -> bool;
+
+	/// The calls dispatched by the wrapper call, such as the ones of `Utility::batch`.
+	fn wrapped_calls(_call: &Call) -> Vec<&Call> {
+		Vec::new()
+	}
+}
+
+/// Denies the XCM if any of its `Transact`, including the nested ones, dispatches a call which is
+/// not allowed by the `Filter` for its origin kind, or can't be decoded.
+///
+/// The calls wrapped by the dispatched one are checked recursively, up to
+/// `MAX_WRAPPED_CALL_DEPTH`.
+pub struct DenyUnsafeTransact<RuntimeCall, Filter>(PhantomData<(RuntimeCall, Filter)>);
+impl<RuntimeCall, Filter> DenyUnsafeTransact<RuntimeCall, Filter>
+where
+	RuntimeCall: Decode,
+	Filter: TransactFilter<RuntimeCall>,
+{
+	fn allows<Call>(instructions: &[Instruction<Call>]) -> bool {
+		instructions.iter().all(|instruction| match instruction {
+			Transact { origin_type, call, .. } => {
+				let mut call: DoubleEncoded<RuntimeCall> = call.clone().into();
+
+				call.ensure_decoded()
+					.map(|call| Self::allows_call(*origin_type, call, 0))
+					.unwrap_or(false)
+			},
+			SetAppendix(xcm) | SetErrorHandler(xcm) => Self::allows(&xcm.0),
+			_ => true,
+		})
+	}
+
+	fn allows_call(origin_kind: OriginKind, call: &RuntimeCall, depth: u32) -> bool {
+		if !Filter::allows(origin_kind, call) {
+			return false;
+		}
+
+		let wrapped_calls = Filter::wrapped_calls(call);
+
+		if wrapped_calls.is_empty() {
+			true
+		} else if depth >= MAX_WRAPPED_CALL_DEPTH {
+			false
+		} else {
+			wrapped_calls
+				.into_iter()
+				.all(|call| Self::allows_call(origin_kind, call, depth.saturating_add(1)))
+		}
+	}
+}
+impl<RuntimeCall, Filter> ShouldExecute for DenyUnsafeTransact<RuntimeCall, Filter>
+where
+	RuntimeCall: Decode,
+	Filter: TransactFilter<RuntimeCall>,
+{
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		message: &mut Xcm<Call>,
+		_max_weight: Weight,
+		_weight_credit: &mut Weight,
+	) -> Result<(), ()> {
+		if Self::allows(&message.0) {
+			Ok(())
+		} else {
+			log::trace!(
+				target: "xcm::barrier",
+				"DenyUnsafeTransact denied the XCM from {:?}",
+				origin,
+			);
+
+			Err(())
+		}
+	}
+}
@@ -0,0 +1,130 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the transact filter module.
+
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn transact(origin_type: OriginKind, call: Call) -> Instruction<()> {
+	Transact { origin_type, require_weight_at_most: 1_000, call: call.encode().into() }
+}
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![1] })
+}
+
+fn set_storage() -> Call {
+	Call::System(frame_system::Call::set_storage { items: vec![] })
+}
+
+fn set_code() -> Call {
+	Call::System(frame_system::Call::set_code { code: vec![] })
+}
+
+fn batch(calls: Vec<Call>) -> Call {
+	Call::Utility(pallet_utility::Call::batch { calls })
+}
+
+fn dispatch_as(call: Call) -> Call {
+	Call::Utility(pallet_utility::Call::dispatch_as {
+		as_origin: Box::new(frame_system::RawOrigin::Root.into()),
+		call: Box::new(call),
+	})
+}
+
+fn check(instructions: Vec<Instruction<()>>) -> Result<(), ()> {
+	Barrier::should_execute(&MultiLocation::parent(), &mut Xcm(instructions), 1_000, &mut 0)
+}
+
+#[test]
+fn allowed_calls_should_pass() {
+	assert_eq!(check(vec![ClearOrigin]), Ok(()));
+	assert_eq!(check(vec![transact(OriginKind::SovereignAccount, remark())]), Ok(()));
+	assert_eq!(check(vec![transact(OriginKind::Superuser, set_storage())]), Ok(()));
+	assert_eq!(
+		check(vec![
+			transact(OriginKind::Native, remark()),
+			SetAppendix(Xcm(vec![transact(OriginKind::Superuser, set_storage())])),
+		]),
+		Ok(())
+	);
+}
+
+#[test]
+fn unsafe_calls_should_be_denied() {
+	// Not allowed for the origin kind.
+	assert_eq!(check(vec![transact(OriginKind::SovereignAccount, set_storage())]), Err(()));
+	// Not allowed for any origin kind.
+	assert_eq!(check(vec![transact(OriginKind::Superuser, set_code())]), Err(()));
+	// Any of the calls is denied.
+	assert_eq!(
+		check(vec![
+			transact(OriginKind::SovereignAccount, remark()),
+			transact(OriginKind::Xcm, set_storage()),
+		]),
+		Err(())
+	);
+	// The nested calls are checked.
+	assert_eq!(
+		check(vec![SetErrorHandler(Xcm(vec![transact(OriginKind::Native, set_code())]))]),
+		Err(())
+	);
+	// Can't be decoded.
+	assert_eq!(
+		check(vec![Transact {
+			origin_type: OriginKind::Superuser,
+			require_weight_at_most: 1_000,
+			call: vec![255, 255].into(),
+		}]),
+		Err(())
+	);
+}
+
+#[test]
+fn wrapped_calls_should_be_checked() {
+	assert_eq!(check(vec![transact(OriginKind::Native, batch(vec![remark(), remark()]))]), Ok(()));
+	assert_eq!(
+		check(vec![transact(OriginKind::Superuser, batch(vec![remark(), set_storage()]))]),
+		Ok(())
+	);
+	// Any of the wrapped calls is denied.
+	assert_eq!(
+		check(vec![transact(OriginKind::SovereignAccount, batch(vec![remark(), set_storage()]))]),
+		Err(())
+	);
+	assert_eq!(check(vec![transact(OriginKind::Superuser, batch(vec![set_code()]))]), Err(()));
+	// The nested wrappers are checked.
+	assert_eq!(
+		check(vec![transact(OriginKind::Superuser, batch(vec![batch(vec![set_code()])]))]),
+		Err(())
+	);
+	// The wrapper denied by the filter.
+	assert_eq!(check(vec![transact(OriginKind::Superuser, dispatch_as(remark()))]), Err(()));
+}
+
+#[test]
+fn too_deep_wrapped_calls_should_be_denied() {
+	let wrap = |depth| (0..depth).fold(remark(), |call, _| batch(vec![call]));
+
+	assert_eq!(check(vec![transact(OriginKind::Native, wrap(MAX_WRAPPED_CALL_DEPTH))]), Ok(()));
+	assert_eq!(
+		check(vec![transact(OriginKind::Native, wrap(MAX_WRAPPED_CALL_DEPTH + 1))]),
+		Err(())
+	);
+}
@@ -0,0 +1,213 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Governable trust table of the XCM reserves and teleporters.
+//!
+//! Replaces `NativeAsset`, which trusts any origin to be the reserve or the teleporter of its own
+//! native asset. An origin is only trusted for the assets matched by its patterns.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- core ---
+use core::marker::PhantomData;
+// --- paritytech ---
+use frame_support::pallet_prelude::*;
+use sp_std::vec::Vec;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::FilterAssetLocation;
+// --- darwinia-network ---
+use crate::xcm_config::LocationPattern;
+
+/// What an origin is trusted as.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum TrustKind {
+	/// The origin is the reserve of the matched assets.
+	Reserve,
+	/// The origin can teleport the matched assets.
+	Teleport,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which can modify the trust table.
+		type ManagerOrigin: EnsureOrigin<Self::Origin>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The location was trusted for the assets matched by the pattern.
+		TrustAdded { kind: TrustKind, location: MultiLocation, assets: LocationPattern },
+		/// The location is no longer trusted for the assets matched by the pattern.
+		TrustRemoved { kind: TrustKind, location: MultiLocation, assets: LocationPattern },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location is already trusted for the pattern.
+		AlreadyTrusted,
+		/// The location is not trusted for the pattern.
+		NotTrusted,
+	}
+
+	/// The asset patterns each location is trusted for.
+	#[pallet::storage]
+	pub type Trusted<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, TrustKind>,
+			NMapKey<Blake2_128Concat, MultiLocation>,
+			NMapKey<Blake2_128Concat, LocationPattern>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig {
+		pub trusted: Vec<(TrustKind, MultiLocation, LocationPattern)>,
+	}
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			Self { trusted: Vec::new() }
+		}
+	}
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			for (kind, location, assets) in &self.trusted {
+				<Trusted<T>>::insert((kind, location, assets), ());
+			}
+		}
+	}
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Trust the location for the assets matched by the pattern.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(10_000))]
+		pub fn add_trust(
+			origin: OriginFor<T>,
+			kind: TrustKind,
+			location: MultiLocation,
+			assets: LocationPattern,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!<Trusted<T>>::contains_key((kind, &location, &assets)),
+				<Error<T>>::AlreadyTrusted
+			);
+
+			<Trusted<T>>::insert((kind, &location, &assets), ());
+
+			Self::deposit_event(Event::TrustAdded { kind, location, assets });
+
+			Ok(().into())
+		}
+
+		/// Stop trusting the location for the assets matched by the pattern.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(10_000))]
+		pub fn remove_trust(
+			origin: OriginFor<T>,
+			kind: TrustKind,
+			location: MultiLocation,
+			assets: LocationPattern,
+		) -> DispatchResultWithPostInfo {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(<Trusted<T>>::contains_key((kind, &location, &assets)), <Error<T>>::NotTrusted);
+
+			<Trusted<T>>::remove((kind, &location, &assets));
+
+			Self::deposit_event(Event::TrustRemoved { kind, location, assets });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// Whether the origin is trusted as the kind for the concrete asset.
+		pub fn is_trusted(kind: TrustKind, asset: &MultiAsset, origin: &MultiLocation) -> bool {
+			let asset_location = match &asset.id {
+				Concrete(location) => location,
+				_ => return false,
+			};
+
+			<Trusted<T>>::iter_key_prefix((kind, origin.clone()))
+				.any(|pattern| pattern.matches(asset_location))
+		}
+
+		/// Seed the trust table of the kind if it's empty.
+		///
+		/// Used by the runtime migrations to move the compile-time trust into the storage.
+		/// Returns the number of the inserted entries.
+		pub fn seed(kind: TrustKind, trusted: Vec<(MultiLocation, LocationPattern)>) -> u32 {
+			if <Trusted<T>>::iter_prefix((kind,)).next().is_some() {
+				return 0;
+			}
+
+			let mut count = 0;
+
+			for (location, assets) in trusted {
+				<Trusted<T>>::insert((kind, location, assets), ());
+
+				count += 1;
+			}
+
+			count
+		}
+	}
+}
+pub use pallet::*;
+
+/// Trusts the reserves in the `Reserve` table, used as the `IsReserve` of the XCM executor.
+pub struct TrustedReserves<T>(PhantomData<T>);
+impl<T: Config> FilterAssetLocation for TrustedReserves<T> {
+	fn filter_asset_location(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		<Pallet<T>>::is_trusted(TrustKind::Reserve, asset, origin)
+	}
+}
+
+/// Trusts the teleporters in the `Teleport` table, used as the `IsTeleporter` of the XCM executor.
+pub struct TrustedTeleporters<T>(PhantomData<T>);
+impl<T: Config> FilterAssetLocation for TrustedTeleporters<T> {
+	fn filter_asset_location(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		<Pallet<T>>::is_trusted(TrustKind::Teleport, asset, origin)
+	}
+}
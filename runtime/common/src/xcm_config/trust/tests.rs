@@ -0,0 +1,201 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the trust module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError::BadOrigin;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn astar() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(2006)))
+}
+
+fn glmr() -> MultiLocation {
+	MultiLocation::new(1, X2(Parachain(MOONBEAM_PARA_ID), PalletInstance(10)))
+}
+
+fn asset(location: MultiLocation) -> MultiAsset {
+	(location, 100).into()
+}
+
+#[test]
+fn genesis_trust_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(TrustedReserves::<Test>::filter_asset_location(
+			&asset(dot()),
+			&MultiLocation::parent()
+		));
+		assert!(TrustedReserves::<Test>::filter_asset_location(&asset(glmr()), &moonbeam()));
+		assert!(TrustedReserves::<Test>::filter_asset_location(&asset(moonbeam()), &moonbeam()));
+
+		// Nothing is trusted to be teleported.
+		assert!(!TrustedTeleporters::<Test>::filter_asset_location(
+			&asset(dot()),
+			&MultiLocation::parent()
+		));
+	});
+}
+
+#[test]
+fn untrusted_origins_should_be_refused() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Moonbeam is not the reserve of DOT.
+		assert!(!TrustedReserves::<Test>::filter_asset_location(&asset(dot()), &moonbeam()));
+		// The relay chain is not the reserve of GLMR.
+		assert!(!TrustedReserves::<Test>::filter_asset_location(
+			&asset(glmr()),
+			&MultiLocation::parent()
+		));
+		// Astar is not trusted at all, even for its own native asset, which `NativeAsset` allows.
+		assert!(!TrustedReserves::<Test>::filter_asset_location(&asset(astar()), &astar()));
+		// Only the concrete assets can be trusted.
+		assert!(!TrustedReserves::<Test>::filter_asset_location(
+			&(Abstract(b"DOT".to_vec()), 100).into(),
+			&MultiLocation::parent()
+		));
+		// Not trusted for the sibling's assets under the relay chain.
+		assert!(!TrustedReserves::<Test>::filter_asset_location(
+			&asset(astar()),
+			&MultiLocation::parent()
+		));
+	});
+}
+
+#[test]
+fn add_trust_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmTrust::add_trust(
+				Origin::signed(ALICE),
+				TrustKind::Reserve,
+				astar(),
+				LocationPattern::Exact(astar())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmTrust::add_trust(
+				Origin::root(),
+				TrustKind::Reserve,
+				moonbeam(),
+				LocationPattern::Within(moonbeam())
+			),
+			<Error<Test>>::AlreadyTrusted
+		);
+
+		assert_ok!(XcmTrust::add_trust(
+			Origin::root(),
+			TrustKind::Reserve,
+			astar(),
+			LocationPattern::Exact(astar())
+		));
+		System::assert_last_event(
+			super::Event::<Test>::TrustAdded {
+				kind: TrustKind::Reserve,
+				location: astar(),
+				assets: LocationPattern::Exact(astar()),
+			}
+			.into(),
+		);
+		assert!(TrustedReserves::<Test>::filter_asset_location(&asset(astar()), &astar()));
+		// Trusted as the reserve only.
+		assert!(!TrustedTeleporters::<Test>::filter_asset_location(&asset(astar()), &astar()));
+	});
+}
+
+#[test]
+fn remove_trust_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmTrust::remove_trust(
+				Origin::signed(ALICE),
+				TrustKind::Reserve,
+				moonbeam(),
+				LocationPattern::Within(moonbeam())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmTrust::remove_trust(
+				Origin::root(),
+				TrustKind::Reserve,
+				moonbeam(),
+				LocationPattern::Exact(moonbeam())
+			),
+			<Error<Test>>::NotTrusted
+		);
+
+		assert_ok!(XcmTrust::remove_trust(
+			Origin::root(),
+			TrustKind::Reserve,
+			moonbeam(),
+			LocationPattern::Within(moonbeam())
+		));
+		System::assert_last_event(
+			super::Event::<Test>::TrustRemoved {
+				kind: TrustKind::Reserve,
+				location: moonbeam(),
+				assets: LocationPattern::Within(moonbeam()),
+			}
+			.into(),
+		);
+		assert!(!TrustedReserves::<Test>::filter_asset_location(&asset(glmr()), &moonbeam()));
+	});
+}
+
+#[test]
+fn seed_should_only_work_on_empty_table() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			XcmTrust::seed(TrustKind::Reserve, vec![(astar(), LocationPattern::Exact(astar()))]),
+			0
+		);
+		assert!(!TrustedReserves::<Test>::filter_asset_location(&asset(astar()), &astar()));
+
+		assert_eq!(
+			XcmTrust::seed(
+				TrustKind::Teleport,
+				vec![(MultiLocation::parent(), LocationPattern::Exact(dot()))]
+			),
+			1
+		);
+		assert!(TrustedTeleporters::<Test>::filter_asset_location(
+			&asset(dot()),
+			&MultiLocation::parent()
+		));
+	});
+}
+
+#[test]
+fn sibling_assets_should_not_be_teleported() {
+	ExtBuilder::default().build().execute_with(|| {
+		// The `ReceiveTeleportedAsset` of the sibling is refused for its own native asset, which
+		// the `FungiblesAdapter` would mint once the asset is registered.
+		assert!(!TrustedTeleporters::<Test>::filter_asset_location(&asset(astar()), &astar()));
+		assert!(!TrustedTeleporters::<Test>::filter_asset_location(
+			&asset(moonbeam()),
+			&moonbeam()
+		));
+		// Even if the sibling is trusted as the reserve of the asset.
+		assert!(TrustedReserves::<Test>::filter_asset_location(&asset(glmr()), &moonbeam()));
+		assert!(!TrustedTeleporters::<Test>::filter_asset_location(&asset(glmr()), &moonbeam()));
+	});
+}
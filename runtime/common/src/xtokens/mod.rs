@@ -0,0 +1,200 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! An `orml_xtokens`-like API to transfer the native token to the sibling parachains.
+//!
+//! The native token is reserve transferred, the destination chain gets it at
+//! `SelfLocationInSibl` + `LocalAssetId`, e.g. `(1, X2(Parachain(2105), PalletInstance(5)))`, and
+//! pays the execution with it.
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// --- paritytech ---
+use frame_support::{pallet_prelude::*, weights::Weight};
+use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+use sp_std::{boxed::Box, vec};
+use xcm::{latest::prelude::*, VersionedMultiLocation};
+use xcm_executor::traits::WeightBounds;
+
+/// The currencies which can be transferred.
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+pub enum CurrencyId {
+	/// The native token of this chain, e.g. *RING* or *CRAB*.
+	Native,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::log;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The balance of the native token.
+		type Balance: Parameter + Member + AtLeast32BitUnsigned + Copy + MaxEncodedLen + Into<u128>;
+
+		/// Required origin for executing XCM messages.
+		type ExecuteXcmOrigin: EnsureOrigin<Self::Origin, Success = MultiLocation>;
+
+		/// The native token location, e.g. `AnchoringSelfReserve`.
+		type LocalAssetId: Get<MultiLocation>;
+
+		/// This chain location relative to sibling chain.
+		type SelfLocationInSibl: Get<MultiLocation>;
+
+		/// Used to calculate the weight required for the local execution of xcm.
+		type Weigher: WeightBounds<Self::Call>;
+
+		type XcmExecutor: ExecuteXcm<Self::Call>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Transferred the currency to the beneficiary on the destination chain.
+		Transferred {
+			sender: T::AccountId,
+			currency: CurrencyId,
+			amount: T::Balance,
+			dest: MultiLocation,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Can not transfer zero amount.
+		ZeroAmount,
+		/// The version of the destination is not supported.
+		BadVersion,
+		/// The destination must be an account on a sibling chain, e.g.
+		/// `(1, X2(Parachain(2004), AccountKey20))`.
+		InvalidDest,
+		/// The message's weight could not be determined.
+		UnweighableMessage,
+		/// Failed to execute the transfer.
+		XcmExecutionFailed,
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer the currency to the account on a sibling chain.
+		///
+		/// The `dest` contains both the chain and the beneficiary, e.g.
+		/// `(1, X2(Parachain(2004), AccountKey20))`. The transferred amount is used to pay the
+		/// execution on the destination chain, limited by the `dest_weight_limit`.
+		#[pallet::weight(Pallet::<T>::weight_of_transfer(dest))]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			currency: CurrencyId,
+			amount: T::Balance,
+			dest: Box<VersionedMultiLocation>,
+			dest_weight_limit: WeightLimit,
+		) -> DispatchResultWithPostInfo {
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin.clone())?;
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), <Error<T>>::ZeroAmount);
+
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| <Error<T>>::BadVersion)?;
+			let mut xcm = Self::transfer_xcm(currency, amount.into(), &dest, dest_weight_limit)?;
+			let weight =
+				T::Weigher::weight(&mut xcm).map_err(|()| <Error<T>>::UnweighableMessage)?;
+
+			T::XcmExecutor::execute_xcm_in_credit(origin_location, xcm, weight, weight)
+				.ensure_complete()
+				.map_err(|error| {
+					log::error!("Failed to transfer {:?} with {:?}", currency, error);
+
+					<Error<T>>::XcmExecutionFailed
+				})?;
+
+			Self::deposit_event(Event::Transferred { sender, currency, amount, dest });
+
+			Ok(().into())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The weight of the local execution of a transfer.
+		pub fn weight_of_transfer(dest: &VersionedMultiLocation) -> Weight {
+			dest.clone()
+				.try_into()
+				.ok()
+				.and_then(|dest| {
+					Self::transfer_xcm(CurrencyId::Native, 0, &dest, WeightLimit::Unlimited).ok()
+				})
+				.and_then(|mut xcm| T::Weigher::weight(&mut xcm).ok())
+				.unwrap_or(0)
+		}
+
+		/// Build the local XCM which withdraws the currency and reserve transfers it to the
+		/// beneficiary on the destination chain.
+		fn transfer_xcm(
+			currency: CurrencyId,
+			amount: u128,
+			dest: &MultiLocation,
+			dest_weight_limit: WeightLimit,
+		) -> Result<Xcm<T::Call>, Error<T>> {
+			let (chain, beneficiary) = match dest {
+				MultiLocation { parents: 1, interior: X2(Parachain(para_id), beneficiary) } => (
+					MultiLocation::new(1, X1(Parachain(*para_id))),
+					MultiLocation::new(0, X1(beneficiary.clone())),
+				),
+				_ => return Err(<Error<T>>::InvalidDest),
+			};
+			let (asset, asset_in_sibl) = match currency {
+				CurrencyId::Native => {
+					let mut asset_in_sibl = T::SelfLocationInSibl::get();
+
+					asset_in_sibl
+						.append_with(T::LocalAssetId::get().interior)
+						.map_err(|_| <Error<T>>::InvalidDest)?;
+
+					(
+						MultiAsset::from((T::LocalAssetId::get(), amount)),
+						MultiAsset::from((asset_in_sibl, amount)),
+					)
+				},
+			};
+
+			Ok(Xcm(vec![
+				WithdrawAsset(asset.into()),
+				DepositReserveAsset {
+					assets: Wild(All),
+					max_assets: 1,
+					dest: chain,
+					xcm: Xcm(vec![
+						BuyExecution { fees: asset_in_sibl, weight_limit: dest_weight_limit },
+						DepositAsset { assets: Wild(All), max_assets: 1, beneficiary },
+					]),
+				},
+			]))
+		}
+	}
+}
+pub use pallet::*;
@@ -0,0 +1,155 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2022 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the xtokens module.
+
+// --- paritytech ---
+use frame_support::{assert_noop, assert_ok, traits::PalletInfoAccess};
+use sp_runtime::DispatchError::BadOrigin;
+use xcm::VersionedMultiLocation;
+use xcm_executor::traits::Convert;
+// --- darwinia-network ---
+use super::{mock::*, *};
+
+fn moonbeam() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(MOONBEAM_PARA_ID)))
+}
+
+fn beneficiary() -> Junction {
+	AccountKey20 { network: NetworkId::Any, key: [1; 20] }
+}
+
+fn dest_of(chain: MultiLocation) -> Box<VersionedMultiLocation> {
+	Box::new(chain.pushed_with_interior(beneficiary()).unwrap().into())
+}
+
+#[test]
+fn transfer_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XTokens::transfer(
+			Origin::signed(ALICE),
+			CurrencyId::Native,
+			100,
+			dest_of(moonbeam()),
+			WeightLimit::Limited(1_000)
+		));
+
+		assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE - 100);
+		// The reserve is kept in the sovereign account of the destination chain.
+		assert_eq!(Balances::free_balance(&LocationToAccountId::convert(moonbeam()).unwrap()), 100);
+
+		let asset_in_sibl: MultiAsset = (
+			MultiLocation::new(
+				1,
+				X2(
+					Parachain(PARA_ID),
+					PalletInstance(<Balances as PalletInfoAccess>::index() as u8),
+				),
+			),
+			100,
+		)
+			.into();
+
+		assert_eq!(
+			sent_xcm(),
+			vec![(
+				moonbeam(),
+				Xcm(vec![
+					ReserveAssetDeposited(asset_in_sibl.clone().into()),
+					ClearOrigin,
+					BuyExecution { fees: asset_in_sibl, weight_limit: WeightLimit::Limited(1_000) },
+					DepositAsset {
+						assets: Wild(All),
+						max_assets: 1,
+						beneficiary: MultiLocation::new(0, X1(beneficiary()))
+					},
+				])
+			)]
+		);
+		System::assert_last_event(
+			super::Event::<Test>::Transferred {
+				sender: ALICE,
+				currency: CurrencyId::Native,
+				amount: 100,
+				dest: MultiLocation::new(1, X2(Parachain(MOONBEAM_PARA_ID), beneficiary())),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_should_reject_invalid_params() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XTokens::transfer(
+				Origin::root(),
+				CurrencyId::Native,
+				100,
+				dest_of(moonbeam()),
+				WeightLimit::Unlimited
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XTokens::transfer(
+				Origin::signed(ALICE),
+				CurrencyId::Native,
+				0,
+				dest_of(moonbeam()),
+				WeightLimit::Unlimited
+			),
+			<Error<Test>>::ZeroAmount
+		);
+		// The relay chain.
+		assert_noop!(
+			XTokens::transfer(
+				Origin::signed(ALICE),
+				CurrencyId::Native,
+				100,
+				dest_of(MultiLocation::parent()),
+				WeightLimit::Unlimited
+			),
+			<Error<Test>>::InvalidDest
+		);
+		// No beneficiary.
+		assert_noop!(
+			XTokens::transfer(
+				Origin::signed(ALICE),
+				CurrencyId::Native,
+				100,
+				Box::new(moonbeam().into()),
+				WeightLimit::Unlimited
+			),
+			<Error<Test>>::InvalidDest
+		);
+		// Insufficient balance.
+		assert_noop!(
+			XTokens::transfer(
+				Origin::signed(ALICE),
+				CurrencyId::Native,
+				INITIAL_BALANCE + 1,
+				dest_of(moonbeam()),
+				WeightLimit::Unlimited
+			),
+			<Error<Test>>::XcmExecutionFailed
+		);
+
+		assert!(sent_xcm().is_empty());
+	});
+}
@@ -0,0 +1,20 @@
+// --- paritytech ---
+use frame_support::PalletId;
+use frame_system::EnsureRoot;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::xcm_config::asset_registry::Config;
+
+frame_support::parameter_types! {
+	pub const AssetRegistryPalletId: PalletId = PalletId(*b"da/asreg");
+}
+
+impl Config for Runtime {
+	type AssetId = ForeignAssetId;
+	type Assets = Assets;
+	type Balance = Balance;
+	type Event = Event;
+	type PalletId = AssetRegistryPalletId;
+	type RegistryOrigin = EnsureRoot<AccountId>;
+	type StringLimit = AssetsStringLimit;
+}
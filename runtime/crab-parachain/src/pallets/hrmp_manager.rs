@@ -0,0 +1,25 @@
+// --- paritytech ---
+use cumulus_primitives_utility::ParentAsUmp;
+use frame_support::weights::Weight;
+use frame_system::EnsureRoot;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::hrmp_manager::Config;
+
+frame_support::parameter_types! {
+	/// The index of `Hrmp` in the relay chain runtime.
+	pub const HrmpPalletIndex: u8 = 60;
+	/// 0.05 KSM, the surplus is refunded to the sovereign account on the relay chain.
+	pub const RelayFee: u128 = 50_000_000_000;
+	pub const RelayCallWeight: Weight = 1_000_000_000;
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type HrmpPalletIndex = HrmpPalletIndex;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type RelayCallWeight = RelayCallWeight;
+	type RelayFee = RelayFee;
+	type SelfParaId = ParachainInfo;
+	type XcmSender = ParentAsUmp<ParachainSystem, PolkadotXcm>;
+}
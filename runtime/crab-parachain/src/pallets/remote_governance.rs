@@ -1,46 +1,143 @@
 // --- paritytech ---
-use frame_support::traits::{EnsureOrigin, Get};
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{Contains, EitherOfDiverse, Get},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
 // --- darwinia-network ---
 use crate::*;
-use dp_common_runtime::remote_governance::Config;
-
-pub struct EnsureSpecific;
-impl<O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>> EnsureOrigin<O>
-	for EnsureSpecific
-{
-	type Success = AccountId;
-
-	fn try_origin(o: O) -> Result<Self::Success, O> {
-		o.into().and_then(|o| match o {
-			RawOrigin::Signed(who)
-				if who
-					== array_bytes::hex_n_into_unchecked(
-						"0xc778fc2665f3f6ee9623594e5d1fab9dbd557149542c5edacbcc543a82c9d780",
-					) =>
-				Ok(who),
-			r => Err(O::from(r)),
-		})
+use bp_messages::MessageNonce;
+use dp_common_runtime::remote_governance::{self, Config, EnsureGuardians, RemoteCallDelay};
+
+pub struct CrabFinalizedNumber;
+impl Get<BlockNumber> for CrabFinalizedNumber {
+	fn get() -> BlockNumber {
+		<pallet_bridge_grandpa::ImportedHeaders<Runtime, WithCrabGrandpa>>::get(
+			<pallet_bridge_grandpa::BestFinalized<Runtime, WithCrabGrandpa>>::get(),
+		)
+		.map(|header| header.number)
+		.unwrap_or_default()
+	}
+}
+
+/// Watch the outbound lane used by the helixbridge and the message router.
+pub struct CrabOutboundLaneNonces;
+impl Get<Option<(MessageNonce, MessageNonce)>> for CrabOutboundLaneNonces {
+	fn get() -> Option<(MessageNonce, MessageNonce)> {
+		let lane = pallet_bridge_messages::OutboundLanes::<Runtime, WithCrabMessages>::get(
+			&BridgeCrabLaneId::get(),
+		);
+
+		Some((lane.latest_received_nonce, lane.latest_generated_nonce))
+	}
+}
+
+/// Upgrading the runtime and changing the XCM configs are timelocked longer than the others.
+///
+/// Vetoing is enacted immediately, so a scheduled call can be vetoed within its delay. The calls
+/// wrapped by `Utility` are timelocked by the same rules.
+pub struct RemoteCallDelayByCategory;
+impl RemoteCallDelay<Call, BlockNumber> for RemoteCallDelayByCategory {
+	fn delay_of(call: &Call) -> BlockNumber {
+		match call {
+			Call::RemoteGovernance(remote_governance::Call::veto_remote_call { .. }) => 0,
+			Call::System(frame_system::Call::set_code { .. })
+			| Call::System(frame_system::Call::set_code_without_checks { .. })
+			| Call::ParachainSystem(cumulus_pallet_parachain_system::Call::authorize_upgrade {
+				..
+			}) => RuntimeUpgradeDelay::get(),
+			Call::XcmAllowlist(_)
+			| Call::XcmDenylist(_)
+			| Call::XcmTrust(_)
+			| Call::XcmRateLimit(_)
+			| Call::AssetRegistry(_)
+			| Call::HrmpManager(_) => XcmConfigDelay::get(),
+			_ => DefaultRemoteCallDelay::get(),
+		}
+	}
+
+	fn wrapped_calls(call: &Call) -> Vec<&Call> {
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls })
+			| Call::Utility(pallet_utility::Call::batch_all { calls })
+			| Call::Utility(pallet_utility::Call::force_batch { calls }) => calls.iter().collect(),
+			Call::Utility(pallet_utility::Call::as_derivative { call, .. })
+			| Call::Utility(pallet_utility::Call::dispatch_as { call, .. }) => sp_std::vec![call.as_ref()],
+			_ => Vec::new(),
+		}
 	}
 }
 
-pub struct CrabBestFinalized;
-impl Get<Hash> for CrabBestFinalized {
-	fn get() -> Hash {
-		<pallet_bridge_grandpa::BestFinalized<Runtime, WithCrabGrandpa>>::get()
+/// The root calls the guardians can make under the emergency mode, to keep the chain and the
+/// bridge running until the bridged governance is back.
+///
+/// Outside of the emergency mode, they are dispatched with the `Guardians` origin, which is only
+/// accepted by the `VetoOrigin`.
+pub struct SafeguardCalls;
+impl Contains<Call> for SafeguardCalls {
+	fn contains(call: &Call) -> bool {
+		match call {
+			Call::System(frame_system::Call::set_code { .. })
+			| Call::System(frame_system::Call::set_code_without_checks { .. })
+			| Call::ParachainSystem(cumulus_pallet_parachain_system::Call::authorize_upgrade {
+				..
+			})
+			| Call::ParachainSystem(
+				cumulus_pallet_parachain_system::Call::enact_authorized_upgrade { .. },
+			)
+			| Call::CollatorSelection(_)
+			| Call::BridgeCrabGrandpa(pallet_bridge_grandpa::Call::set_operational { .. })
+			| Call::BridgeCrabMessages(pallet_bridge_messages::Call::set_operating_mode {
+				..
+			})
+			| Call::RemoteGovernance(remote_governance::Call::veto_remote_call { .. }) => true,
+			_ => false,
+		}
 	}
 }
 
 frame_support::parameter_types! {
+	pub const RuntimeUpgradeDelay: BlockNumber = 2 * DAYS;
+	pub const XcmConfigDelay: BlockNumber = DAYS;
+	pub const DefaultRemoteCallDelay: BlockNumber = 12 * HOURS;
+	/// The scheduled calls enacted in a block are limited to a quarter of the block weight.
+	pub const MaxScheduledWeight: Weight = MAXIMUM_BLOCK_WEIGHT / 4;
+	/// The initial guardian, which made the emergency safeguard calls before the M-of-N
+	/// guardians were introduced.
+	pub InitialGuardian: AccountId = array_bytes::hex_n_into_unchecked(
+		"0xc778fc2665f3f6ee9623594e5d1fab9dbd557149542c5edacbcc543a82c9d780"
+	);
+	pub const MaxGuardians: u32 = 16;
+	pub const MaxProposalsPerGuardian: u32 = 4;
+	pub const SafeguardProposalLifetime: BlockNumber = DAYS;
+	/// The block time of the bridged chain.
+	pub const BridgedBlockTime: u64 = 6_000;
+	/// 1 day of the bridged blocks.
+	pub const EmergencyEnterLag: u32 = 14_400;
+	pub const EmergencyLeaveLag: u32 = 3_600;
+	pub const LaneStallThreshold: BlockNumber = DAYS;
 	pub const CheckInterval: BlockNumber = DAYS;
 }
 
 impl Config for Runtime {
 	type BridgeAccountIdConverter = bp_crab::AccountIdConverter;
-	type BridgeFinalized = CrabBestFinalized;
+	type BridgeFinalizedNumber = CrabFinalizedNumber;
+	type BridgedBlockTime = BridgedBlockTime;
 	type BridgedChainId = CrabChainId;
 	type Call = Call;
 	type CheckInterval = CheckInterval;
-	type EmergencySafeguardOrigin = EnsureSpecific;
+	type EmergencyEnterLag = EmergencyEnterLag;
+	type EmergencyLeaveLag = EmergencyLeaveLag;
 	type Event = Event;
+	type LaneStallThreshold = LaneStallThreshold;
+	type MaxGuardians = MaxGuardians;
+	type MaxProposalsPerGuardian = MaxProposalsPerGuardian;
+	type MaxScheduledWeight = MaxScheduledWeight;
+	type Origin = Origin;
+	type OutboundLaneNonces = CrabOutboundLaneNonces;
+	type RemoteCallDelay = RemoteCallDelayByCategory;
+	type SafeguardCallFilter = SafeguardCalls;
+	type SafeguardProposalLifetime = SafeguardProposalLifetime;
+	type UnixTime = Timestamp;
+	type VetoOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureGuardians>;
 }
@@ -0,0 +1,14 @@
+// --- paritytech ---
+use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds};
+use xcm_executor::XcmExecutor;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::xcm_config::asset_trap::{AccountIdToLocalLocation, Config};
+
+impl Config for Runtime {
+	type AccountIdToMultiLocation = AccountIdToLocalLocation<AccountId>;
+	type Event = Event;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
@@ -0,0 +1,40 @@
+// --- paritytech ---
+use frame_system::EnsureRoot;
+use xcm::latest::prelude::*;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::xcm_config::{
+	trust::{Config, TrustKind},
+	LocationPattern,
+};
+
+/// The reserves trusted by `NativeAsset` before the trust became governable, e.g. the relay chain
+/// and the partner chains for their own native assets.
+/// Seeds the genesis and the storage of the live chain.
+///
+/// No teleporter is seeded, nothing is teleported to this chain. Trusting the relay chain or a
+/// sibling would let it mint the registered foreign asset without the reserve.
+pub fn default_xcm_trust() -> Vec<(TrustKind, MultiLocation, LocationPattern)> {
+	let partners = [
+		// Moonriver location
+		MultiLocation::new(1, X1(Parachain(2023))),
+		// Astar Shiden location
+		MultiLocation::new(1, X1(Parachain(2007))),
+	];
+	let mut trusted = sp_std::vec![(
+		TrustKind::Reserve,
+		MultiLocation::parent(),
+		LocationPattern::Exact(MultiLocation::parent()),
+	)];
+
+	for partner in partners {
+		trusted.push((TrustKind::Reserve, partner.clone(), LocationPattern::Exact(partner)));
+	}
+
+	trusted
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
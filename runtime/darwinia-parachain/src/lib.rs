@@ -24,8 +24,8 @@ pub use pallets::*;
 
 pub mod weights;
 
-// pub mod migrations;
-// pub use migrations::*;
+pub mod migrations;
+pub use migrations::*;
 
 pub mod bridges_message;
 pub use bridges_message::*;
@@ -107,6 +107,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	CustomOnRuntimeUpgrade,
 >;
 
 type Ring = Balances;
@@ -117,7 +118,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: sp_runtime::create_runtime_str!("Darwinia Parachain"),
 	impl_name: sp_runtime::create_runtime_str!("Darwinia Parachain"),
 	authoring_version: 1,
-	spec_version: 5_3_7_0,
+	spec_version: 5_3_7_1,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -151,7 +152,7 @@ frame_support::construct_runtime! {
 		Session: pallet_session::{Pallet, Call, Storage, Event, Config<T>} = 9,
 		Aura: pallet_aura::{Pallet, Storage, Config<T>} = 10,
 		AuraExt: cumulus_pallet_aura_ext::{Pallet, Storage, Config} = 11,
-		RemoteGovernance: dp_common_runtime::remote_governance::{Pallet, Call, Storage, Event<T>} = 25,
+		RemoteGovernance: dp_common_runtime::remote_governance::{Pallet, Call, Storage, Event<T>, Origin, Config<T>} = 25,
 
 		// XCM things.
 		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 12,
@@ -174,6 +175,15 @@ frame_support::construct_runtime! {
 
 		MessageRouter: dp_common_runtime::message_router::{Pallet, Call, Storage, Event<T>} = 26,
 		SoloToPara: cumulus_pallet_solo_to_para::{Pallet, Call, Storage, Event} = 27,
+		XcmAllowlist: dp_common_runtime::xcm_config::allowlist::{Pallet, Call, Storage, Config, Event<T>} = 28,
+		XcmDenylist: dp_common_runtime::xcm_config::denylist::{Pallet, Call, Storage, Event<T>} = 29,
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 30,
+		AssetRegistry: dp_common_runtime::xcm_config::asset_registry::{Pallet, Call, Storage, Event<T>} = 31,
+		XcmTrust: dp_common_runtime::xcm_config::trust::{Pallet, Call, Storage, Config, Event<T>} = 32,
+		XTokens: dp_common_runtime::xtokens::{Pallet, Call, Event<T>} = 33,
+		XcmAssetTrap: dp_common_runtime::xcm_config::asset_trap::{Pallet, Call, Storage, Event<T>} = 34,
+		XcmRateLimit: dp_common_runtime::xcm_config::rate_limit::{Pallet, Call, Storage, Event<T>} = 35,
+		HrmpManager: dp_common_runtime::hrmp_manager::{Pallet, Call, Event<T>} = 36,
 	}
 }
 
@@ -277,6 +287,24 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl dp_common_runtime::xcm_config::denylist::XcmDenylistApi<Block> for Runtime {
+		fn rules_with_hits() -> Vec<(
+			dp_common_runtime::xcm_config::denylist::RuleId,
+			dp_common_runtime::xcm_config::denylist::DenyRule,
+			u64,
+		)> {
+			XcmDenylist::rules_with_hits()
+		}
+	}
+
+	impl dp_common_runtime::xcm_config::asset_trap::XcmTrappedAssetsApi<Block> for Runtime {
+		fn trapped_assets(
+			origin: Option<xcm::latest::MultiLocation>,
+		) -> Vec<dp_common_runtime::xcm_config::asset_trap::TrappedAssets> {
+			XcmAssetTrap::trapped_assets(origin)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -0,0 +1,153 @@
+// --- paritytech ---
+#[allow(unused)]
+use frame_support::{
+	migration,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+// --- darwinia-network ---
+#[allow(unused)]
+use crate::*;
+
+pub struct CustomOnRuntimeUpgrade;
+impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			XcmAllowlist::on_chain_storage_version() <= XcmAllowlist::current_storage_version(),
+			"XcmAllowlist has a newer storage version"
+		);
+		frame_support::ensure!(
+			XcmTrust::on_chain_storage_version() <= XcmTrust::current_storage_version(),
+			"XcmTrust has a newer storage version"
+		);
+		frame_support::ensure!(
+			RemoteGovernance::on_chain_storage_version()
+				<= RemoteGovernance::current_storage_version(),
+			"RemoteGovernance has a newer storage version"
+		);
+
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			XcmAllowlist::on_chain_storage_version() == XcmAllowlist::current_storage_version(),
+			"XcmAllowlist is not migrated"
+		);
+		frame_support::ensure!(
+			XcmTrust::on_chain_storage_version() == XcmTrust::current_storage_version(),
+			"XcmTrust is not migrated"
+		);
+		frame_support::ensure!(
+			RemoteGovernance::on_chain_storage_version()
+				== RemoteGovernance::current_storage_version(),
+			"RemoteGovernance is not migrated"
+		);
+		frame_support::ensure!(
+			!RemoteGovernance::guardians().is_empty(),
+			"RemoteGovernance has no guardian"
+		);
+
+		Ok(())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		migrate()
+	}
+}
+
+fn migrate() -> Weight {
+	migrate_xcm_allowlist()
+		.saturating_add(migrate_xcm_trust())
+		.saturating_add(migrate_remote_governance())
+	// RuntimeBlockWeights::get().max_block
+}
+
+/// Seed the allowlists with the ones used before they became governable.
+///
+/// Only runs once, the allowlists emptied by the governance later are kept.
+fn migrate_xcm_allowlist() -> Weight {
+	use dp_common_runtime::xcm_config::allowlist::AllowlistKind;
+
+	let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+	if XcmAllowlist::on_chain_storage_version() >= XcmAllowlist::current_storage_version() {
+		return db_weight.reads(1);
+	}
+
+	let mut count = 0;
+
+	for kind in [AllowlistKind::DescendOrigin, AllowlistKind::Subscription] {
+		count += XcmAllowlist::seed(
+			kind,
+			default_xcm_allowlist()
+				.into_iter()
+				.filter_map(|(k, location)| if k == kind { Some(location) } else { None })
+				.collect(),
+		);
+	}
+
+	XcmAllowlist::current_storage_version().put::<XcmAllowlist>();
+
+	db_weight.reads_writes(3, count as u64 + 1)
+}
+
+/// Seed the trust table with the reserves trusted by `NativeAsset` before it became governable, no
+/// teleporter is trusted.
+///
+/// Only runs once, the trust removed by the governance later is kept.
+fn migrate_xcm_trust() -> Weight {
+	use dp_common_runtime::xcm_config::trust::TrustKind;
+
+	let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+	if XcmTrust::on_chain_storage_version() >= XcmTrust::current_storage_version() {
+		return db_weight.reads(1);
+	}
+
+	let mut count = 0;
+
+	for kind in [TrustKind::Reserve, TrustKind::Teleport] {
+		count += XcmTrust::seed(
+			kind,
+			default_xcm_trust()
+				.into_iter()
+				.filter(|(k, ..)| *k == kind)
+				.map(|(_, location, assets)| (location, assets))
+				.collect(),
+		);
+	}
+
+	XcmTrust::current_storage_version().put::<XcmTrust>();
+
+	db_weight.reads_writes(3, count as u64 + 1)
+}
+
+/// Set the initial guardian, which replaces the emergency safeguard account, and remove the
+/// `PreviousBridgeFinalized` replaced by `ProgressAnchor`.
+///
+/// Only runs once, the guardians changed by the governance later are kept. The chains built from
+/// the genesis get their guardians from the `RemoteGovernanceConfig` instead.
+fn migrate_remote_governance() -> Weight {
+	use dp_common_runtime::remote_governance::{GuardianThreshold, Guardians};
+
+	let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+	if RemoteGovernance::on_chain_storage_version() >= RemoteGovernance::current_storage_version() {
+		return db_weight.reads(1);
+	}
+
+	frame_support::storage::unhashed::kill(&frame_support::storage::storage_prefix(
+		b"RemoteGovernance",
+		b"PreviousBridgeFinalized",
+	));
+
+	<Guardians<Runtime>>::put(sp_std::vec![InitialGuardian::get()]);
+	<GuardianThreshold<Runtime>>::put(1);
+
+	RemoteGovernance::current_storage_version().put::<RemoteGovernance>();
+
+	db_weight.reads_writes(1, 4)
+}
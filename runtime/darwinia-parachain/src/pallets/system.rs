@@ -30,6 +30,10 @@ pub const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 /// We allow for .5 seconds of compute with a 12 second average block time.
 pub const MAXIMUM_BLOCK_WEIGHT: Weight = WEIGHT_PER_SECOND / 2;
 
+/// The transfers are not opened yet, so the signed calls of `Assets`, `AssetRegistry`, `XTokens`
+/// and `XcmAssetTrap` stay disabled with `Balances` and `PolkadotXcm`. The foreign assets are
+/// still received and traded by the XCM executor, and registered by the root calls, which bypass
+/// this filter. Whitelist them here together with the native transfers.
 pub enum CallFilter {}
 impl frame_support::traits::Contains<Call> for CallFilter {
 	fn contains(call: &Call) -> bool {
@@ -0,0 +1,10 @@
+// --- paritytech ---
+use frame_system::EnsureRoot;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::xcm_config::rate_limit::Config;
+
+impl Config for Runtime {
+	type Event = Event;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
@@ -0,0 +1,33 @@
+// --- paritytech ---
+use frame_system::EnsureRoot;
+use pallet_assets::Config;
+// --- darwinia-network ---
+use crate::*;
+
+/// The local id of the foreign assets, registered by `AssetRegistry`.
+pub type ForeignAssetId = u32;
+
+frame_support::parameter_types! {
+	pub const AssetDeposit: Balance = dc_primitives::pangolin_deposit(1, 190);
+	pub const AssetAccountDeposit: Balance = dc_primitives::pangolin_deposit(1, 16);
+	pub const MetadataDepositBase: Balance = dc_primitives::pangolin_deposit(1, 68);
+	pub const MetadataDepositPerByte: Balance = dc_primitives::pangolin_deposit(0, 1);
+	pub const AssetsStringLimit: u32 = 50;
+}
+
+impl Config for Runtime {
+	type ApprovalDeposit = ExistentialDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type AssetDeposit = AssetDeposit;
+	type AssetId = ForeignAssetId;
+	type Balance = Balance;
+	type Currency = Balances;
+	type Event = Event;
+	type Extra = ();
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Freezer = ();
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type StringLimit = AssetsStringLimit;
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
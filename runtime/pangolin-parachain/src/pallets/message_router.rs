@@ -1,18 +1,41 @@
 // --- paritytech ---
-use frame_support::{traits::PalletInfoAccess, weights::Weight};
-use frame_system::EnsureRoot;
+use frame_support::{
+	traits::{EitherOfDiverse, PalletInfoAccess, SortedMembers},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use pallet_xcm::EnsureXcm;
 use xcm::prelude::*;
 use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, LocationInverter};
 use xcm_executor::XcmExecutor;
 // --- darwinia-network ---
 use crate::*;
-use dp_common_runtime::message_router::Config;
+use bp_message_dispatch::CallOrigin;
+use bp_runtime::messages::DispatchFeePayment;
+use bridges_message::pangolin::ToPangolinMessagePayload;
+use dp_common_runtime::message_router::{Config, CreateBridgePayload};
+
+pub struct ToPangolinRouterPayload;
+impl CreateBridgePayload<AccountId, AccountPublic, Signature> for ToPangolinRouterPayload {
+	type Payload = ToPangolinMessagePayload;
+
+	fn create(
+		origin: CallOrigin<AccountId, AccountPublic, Signature>,
+		spec_version: u32,
+		weight: u64,
+		call: Vec<u8>,
+		dispatch_fee_payment: DispatchFeePayment,
+	) -> Result<Self::Payload, &'static str> {
+		Ok(ToPangolinMessagePayload { spec_version, weight, origin, call, dispatch_fee_payment })
+	}
+}
 
 frame_support::parameter_types! {
 	// https://github.com/PureStake/moonbeam/blob/master/runtime/moonbase/src/xcm_config.rs#L214
 	pub MoonbeamUnitWeightCost: Weight = 200_000_000;
 	// https://github.com/AstarNetwork/Astar/blob/master/runtime/shibuya/src/xcm_config.rs#L108
 	pub RocstarUnitWeightCost: Weight = 1_000_000_000;
+	pub const MaxForwardBatch: u32 = 8;
 	pub SelfLocationInSibl: MultiLocation = MultiLocation::new(
 		1,
 		X1(Parachain(ParachainInfo::parachain_id().into()))
@@ -29,22 +52,51 @@ frame_support::parameter_types! {
 		1,
 		X1(Parachain(2006))
 	);
+	/// The accounts which feed the units per second of the target chains, set by the governance.
+	pub storage FeederAccounts: Vec<AccountId> = Vec::new();
+}
+
+/// The feeders in `FeederAccounts`.
+pub struct Feeders;
+impl SortedMembers<AccountId> for Feeders {
+	fn sorted_members() -> Vec<AccountId> {
+		let mut feeders = FeederAccounts::get();
+
+		feeders.sort();
+
+		feeders
+	}
+
+	fn contains(who: &AccountId) -> bool {
+		FeederAccounts::get().contains(who)
+	}
 }
 
 impl Config for Runtime {
 	type AstarLocation = RocstarLocation;
 	type AstarWeigher = FixedWeightBounds<RocstarUnitWeightCost, Call, MaxInstructions>;
+	type BridgeForwardOrigin = EnsureXcm<AllowDescendOriginAccounts>;
+	type BridgeMessageLaneId = BridgePangolinLaneId;
+	type BridgedAccountIdConverter = bp_pangolin::AccountIdConverter;
+	type BridgedChainId = PangolinChainId;
 	type ConfigModifierOrigin = EnsureRoot<AccountId>;
 	type Event = Event;
 	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type FeederOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<Feeders, AccountId>>;
 	type LocalAssetId = AnchoringSelfReserve;
 	type LocalWeigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
 	type LocationInverter = LocationInverter<Ancestry>;
+	type LocationToAccountId = LocationToAccountId;
+	type MaxForwardBatch = MaxForwardBatch;
+	type MessagesBridge = BridgePangolinMessages;
 	type MoonbeamLocation = MoonbaseAlphaLocation;
 	type MoonbeamWeigher = FixedWeightBounds<MoonbeamUnitWeightCost, Call, MaxInstructions>;
+	type OutboundPayloadCreator = ToPangolinRouterPayload;
+	type RingCurrency = Ring;
 	type SelfLocationInSibl = SelfLocationInSibl;
 	// Dont update the weights.
 	type WeightInfo = ();
 	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmFeederOrigin = EnsureXcm<AllowDescendOrigin>;
 	type XcmSender = XcmRouter;
 }
@@ -78,3 +78,30 @@ pub use message_router::*;
 
 pub mod solo_to_para;
 pub use solo_to_para::*;
+
+pub mod xcm_allowlist;
+pub use xcm_allowlist::*;
+
+pub mod xcm_denylist;
+pub use xcm_denylist::*;
+
+pub mod assets;
+pub use assets::*;
+
+pub mod asset_registry;
+pub use asset_registry::*;
+
+pub mod xcm_trust;
+pub use xcm_trust::*;
+
+pub mod xtokens;
+pub use xtokens::*;
+
+pub mod xcm_asset_trap;
+pub use xcm_asset_trap::*;
+
+pub mod xcm_rate_limit;
+pub use xcm_rate_limit::*;
+
+pub mod hrmp_manager;
+pub use hrmp_manager::*;
@@ -2,23 +2,35 @@
 use cumulus_pallet_xcm::Origin as CumulusOrigin;
 use cumulus_primitives_utility::ParentAsUmp;
 use frame_support::{
-	traits::{Everything, PalletInfoAccess},
+	traits::{Everything, Nothing, PalletInfoAccess},
 	weights::{ConstantMultiplier, Weight},
 };
 use pallet_xcm::{Config, CurrentXcmVersion, XcmPassthrough};
 use polkadot_parachain::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
-use sp_runtime::traits::ConstU128;
+use sp_runtime::traits::{AccountIdConversion, ConstU128};
 use xcm::latest::prelude::*;
 use xcm_builder::*;
-use xcm_executor::{Config as XcmCExecutorConfig, XcmExecutor};
+use xcm_executor::{traits::JustTry, Config as XcmCExecutorConfig, XcmExecutor};
 // --- darwinia-network ---
 use crate::*;
 use dp_common_runtime::{
 	message_router::{
-		barriers::AllowDescendOriginPaidExecutionFrom, location_conversion::AccountKey20Derive,
+		barriers::AllowDescendOriginPaidExecutionFrom,
+		location_conversion::SiblingAccountKey20Derive,
+	},
+	xcm_config::{
+		allowlist::{
+			DescendOriginAccountsAllowlist, DescendOriginAllowlist, SubscriptionAllowlist,
+		},
+		asset_registry::{AsAssetId, ForeignAssetTrader, RevenueTo},
+		asset_trap::IndexedAssetTrap,
+		denylist::DenyByRules,
+		rate_limit::RateLimited,
+		transact_filter::{DenyUnsafeTransact, TransactFilter},
+		trust::{TrustedReserves, TrustedTeleporters},
+		DenyReserveTransferToRelayChain, DenyThenTry,
 	},
-	xcm_config::{DenyReserveTransferToRelayChain, DenyThenTry},
 };
 
 /// Converts a local signed origin into an XCM multilocation.
@@ -39,6 +51,25 @@ pub type LocalAssetTransactor = CurrencyAdapter<
 	(),
 >;
 
+/// Means for transacting the foreign assets registered in `AssetRegistry`.
+pub type ForeignAssetsTransactor = FungiblesAdapter<
+	// Use this fungibles implementation:
+	Assets,
+	// Use this asset when it is a registered foreign asset:
+	ConvertedConcreteAssetId<ForeignAssetId, Balance, AsAssetId<Runtime>, JustTry>,
+	// Convert an XCM MultiLocation into a local account id:
+	LocationToAccountId,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId,
+	// We don't track any teleports of the foreign assets.
+	Nothing,
+	// The account to use for tracking teleports.
+	CheckingAccount,
+>;
+
+/// Means for transacting the native and the foreign assets.
+pub type AssetTransactors = (LocalAssetTransactor, ForeignAssetsTransactor);
+
 /// The means for routing XCM messages which are not for local execution into the right message
 /// queues.
 pub type XcmRouter = (
@@ -48,21 +79,69 @@ pub type XcmRouter = (
 	XcmpQueue,
 );
 
+/// The governable rules of `XcmDenylist` are checked after the hard-coded ones, then the
+/// executions are limited by the weight budgets of `XcmRateLimit`.
 pub type Barrier = DenyThenTry<
 	DenyReserveTransferToRelayChain,
-	(
-		TakeWeightCredit,
-		AllowTopLevelPaidExecutionFrom<Everything>,
-		AllowDescendOriginPaidExecutionFrom<AllowDescendOrigin>,
-		// Parent and its exec plurality get free execution
-		AllowUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
-		// Expected responses are OK.
-		AllowKnownQueryResponses<PolkadotXcm>,
-		// Subscriptions for version tracking are OK.
-		AllowSubscriptionsFrom<ParentOrSiblings>,
-	),
+	DenyThenTry<
+		DenyUnsafeTransact<Call, XcmTransactFilter>,
+		DenyThenTry<DenyByRules<Runtime>, RateLimited<Runtime, AllowedExecution>>,
+	>,
 >;
 
+/// The calls the XCM `Transact` is allowed to dispatch, by the kind of the dispatch origin.
+///
+/// The other origins than `Superuser` are still restricted by the `BaseCallFilter`. The calls
+/// wrapped by `Utility`, `Proxy` and `Multisig` are checked by the same rules.
+pub struct XcmTransactFilter;
+impl TransactFilter<Call> for XcmTransactFilter {
+	fn allows(origin_kind: OriginKind, call: &Call) -> bool {
+		match call {
+			// Never dispatched by XCM.
+			Call::Sudo(_) | Call::RemoteGovernance(_) => false,
+			// Switches the origin of the wrapped call.
+			Call::Utility(pallet_utility::Call::dispatch_as { .. }) => false,
+			// The wrapped call is opaque, can't be checked.
+			Call::Multisig(pallet_multisig::Call::as_multi { .. })
+			| Call::Multisig(pallet_multisig::Call::approve_as_multi { .. }) => false,
+			// Only the relay chain governance can manage the XCM configs.
+			Call::XcmAllowlist(_)
+			| Call::XcmDenylist(_)
+			| Call::XcmTrust(_)
+			| Call::XcmRateLimit(_)
+			| Call::HrmpManager(_)
+			| Call::AssetRegistry(_) => origin_kind == OriginKind::Superuser,
+			_ => true,
+		}
+	}
+
+	fn wrapped_calls(call: &Call) -> Vec<&Call> {
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls })
+			| Call::Utility(pallet_utility::Call::batch_all { calls })
+			| Call::Utility(pallet_utility::Call::force_batch { calls }) => calls.iter().collect(),
+			Call::Utility(pallet_utility::Call::as_derivative { call, .. })
+			| Call::Proxy(pallet_proxy::Call::proxy { call, .. })
+			| Call::Proxy(pallet_proxy::Call::proxy_announced { call, .. })
+			| Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) =>
+				sp_std::vec![call.as_ref()],
+			_ => Vec::new(),
+		}
+	}
+}
+
+pub type AllowedExecution = (
+	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<Everything>,
+	AllowDescendOriginPaidExecutionFrom<AllowDescendOrigin>,
+	// Parent and its exec plurality get free execution
+	AllowUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
+	// Expected responses are OK.
+	AllowKnownQueryResponses<PolkadotXcm>,
+	// Subscriptions for version tracking from the allowlisted locations are OK.
+	AllowSubscriptionsFrom<SubscriptionAllowlist<Runtime>>,
+);
+
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -71,10 +150,12 @@ pub type LocationToAccountId = (
 	ParentIsPreset<AccountId>,
 	// Sibling parachain origins convert to AccountId via the `ParaId::into`.
 	SiblingParachainConvertsVia<Sibling, AccountId>,
+	// Derive AccountKey20 to AccountId32, mixed with the para id of the sibling chain. It goes
+	// before `AccountId32Aliases`, whose `reverse` accepts any account, so the derived accounts
+	// are reversed to their AccountKey20.
+	SiblingAccountKey20Derive<AccountId, AccountKey20DeriveCompat>,
 	// Straight up local `AccountId32` origins just alias directly to `AccountId`.
 	AccountId32Aliases<RelayNetwork, AccountId>,
-	// Derive AccountKey20 to AccountId32
-	AccountKey20Derive<AccountId>,
 );
 
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
@@ -112,6 +193,14 @@ frame_support::parameter_types! {
 	// One XCM operation is 1_000_000 weight - almost certainly a conservative estimate.
 	pub UnitWeightCost: Weight = 1_000_000_000;
 	pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+	/// The foreign assets paid for the execution go to the treasury, the collators are paid in the
+	/// native token only.
+	pub ForeignFeesReceiver: AccountId = TreasuryPalletId::get().into_account_truncating();
+	/// Derive the sibling chain AccountKey20 without the para id, the same as the previous
+	/// derivation. Once it is turned off, the governance moves the funds of the legacy accounts
+	/// to the paras which funded them by `MessageRouter::migrate_legacy_account`.
+	pub storage AccountKey20DeriveCompat: bool = true;
 }
 
 frame_support::match_types! {
@@ -120,49 +209,41 @@ frame_support::match_types! {
 		MultiLocation { parents: 1, interior: X1(Plurality { id: BodyId::Executive, .. }) }
 	};
 }
-frame_support::match_types! {
-	pub type ParentOrSiblings: impl Contains<MultiLocation> = {
-		MultiLocation { parents: 1, interior: Here } |
-		MultiLocation { parents: 1, interior: X1(_) }
-	};
-}
-frame_support::match_types! {
-	pub type AllowDescendOrigin: impl Contains<MultiLocation> = {
-		// MoonbaseAlpha location
-		MultiLocation {
-			parents: 1,
-			interior: X1(Parachain(1000))
-		} |
-		// Astar RocstarTestnet location
-		MultiLocation {
-			parents: 1,
-			interior: X1(Parachain(2006))
-		}
-	};
-}
+/// The sibling chains allowed to descend the origin, managed by `XcmAllowlist`.
+pub type AllowDescendOrigin = DescendOriginAllowlist<Runtime>;
+/// The `AccountKey20` accounts of the sibling chains allowed to descend the origin.
+pub type AllowDescendOriginAccounts = DescendOriginAccountsAllowlist<Runtime>;
+
+/// Pay the execution with the native token first, then with the foreign assets.
+pub type Traders = (
+	UsingComponents<
+		ConstantMultiplier<Balance, ConstU128<{ BASE_WEIGHT_FEE }>>,
+		AnchoringSelfReserve,
+		AccountId,
+		Balances,
+		ToAuthor<Runtime>,
+	>,
+	ForeignAssetTrader<Runtime, RevenueTo<ForeignAssetsTransactor, AccountId, ForeignFeesReceiver>>,
+);
+
+/// Index the trapped assets, so they can be listed and reclaimed by `XcmAssetTrap`.
+pub type AssetTrap = IndexedAssetTrap<Runtime, PolkadotXcm>;
 
 pub struct XcmConfig;
 impl XcmCExecutorConfig for XcmConfig {
-	type AssetClaims = PolkadotXcm;
+	type AssetClaims = AssetTrap;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor;
-	type AssetTrap = PolkadotXcm;
+	type AssetTransactor = AssetTransactors;
+	type AssetTrap = AssetTrap;
 	type Barrier = Barrier;
 	type Call = Call;
-	type IsReserve = NativeAsset;
-	type IsTeleporter = NativeAsset;
-	// <- should be enough to allow teleportation of KSM
+	type IsReserve = TrustedReserves<Runtime>;
+	type IsTeleporter = TrustedTeleporters<Runtime>;
 	type LocationInverter = LocationInverter<Ancestry>;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	type ResponseHandler = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
-	type Trader = UsingComponents<
-		ConstantMultiplier<Balance, ConstU128<{ BASE_WEIGHT_FEE }>>,
-		AnchoringSelfReserve,
-		AccountId,
-		Balances,
-		ToAuthor<Runtime>,
-	>;
+	type Trader = Traders;
 	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
 	type XcmSender = XcmRouter;
 }
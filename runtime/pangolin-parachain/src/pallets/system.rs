@@ -1,7 +1,7 @@
 // --- paritytech ---
 use cumulus_pallet_parachain_system::ParachainSetCode;
 use frame_support::{
-	traits::{ConstU32, Everything},
+	traits::{ConstU32, Contains},
 	weights::{constants::WEIGHT_PER_SECOND, DispatchClass, Weight},
 };
 use frame_system::{
@@ -30,6 +30,14 @@ pub const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 /// We allow for .5 seconds of compute with a 12 second average block time.
 pub const MAXIMUM_BLOCK_WEIGHT: Weight = WEIGHT_PER_SECOND / 2;
 
+pub enum CallFilter {}
+impl Contains<Call> for CallFilter {
+	fn contains(call: &Call) -> bool {
+		// The foreign assets can only be created by `AssetRegistry`.
+		!matches!(call, Call::Assets(pallet_assets::Call::create { .. }))
+	}
+}
+
 frame_support::parameter_types! {
 	pub const BlockHashCount: BlockNumber = 2400;
 	pub const Version: RuntimeVersion = VERSION;
@@ -59,7 +67,7 @@ frame_support::parameter_types! {
 impl Config for Runtime {
 	type AccountData = AccountData<Balance>;
 	type AccountId = AccountId;
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = CallFilter;
 	type BlockHashCount = BlockHashCount;
 	type BlockLength = RuntimeBlockLength;
 	type BlockNumber = BlockNumber;
@@ -0,0 +1,37 @@
+// --- paritytech ---
+use frame_system::EnsureRoot;
+use xcm::latest::prelude::*;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::xcm_config::{
+	allowlist::{AllowlistKind, Config},
+	LocationPattern,
+};
+
+/// The allowlists used before they became governable.
+/// Seeds the genesis and the storage of the live chain.
+///
+/// The relay chain and all the siblings can subscribe, the same as `ParentOrSiblings`.
+pub fn default_xcm_allowlist() -> Vec<(AllowlistKind, LocationPattern)> {
+	let partners = [
+		// MoonbaseAlpha location
+		MultiLocation::new(1, X1(Parachain(1000))),
+		// Astar Rocstar location
+		MultiLocation::new(1, X1(Parachain(2006))),
+	];
+	let mut allowlist = sp_std::vec![(
+		AllowlistKind::Subscription,
+		LocationPattern::Within(MultiLocation::parent())
+	)];
+
+	for partner in partners {
+		allowlist.push((AllowlistKind::DescendOrigin, LocationPattern::Exact(partner)));
+	}
+
+	allowlist
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
@@ -0,0 +1,12 @@
+// --- paritytech ---
+use frame_support::traits::ConstU32;
+use frame_system::EnsureRoot;
+// --- darwinia-network ---
+use crate::*;
+use dp_common_runtime::xcm_config::denylist::Config;
+
+impl Config for Runtime {
+	type Event = Event;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type MaxRules = ConstU32<16>;
+}
@@ -0,0 +1,19 @@
+// --- paritytech ---
+use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds};
+use xcm_executor::XcmExecutor;
+// --- darwinia-network ---
+use crate::{
+	pallets::{message_router::SelfLocationInSibl, polkadot_xcm::AnchoringSelfReserve},
+	*,
+};
+use dp_common_runtime::xtokens::Config;
+
+impl Config for Runtime {
+	type Balance = Balance;
+	type Event = Event;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+	type LocalAssetId = AnchoringSelfReserve;
+	type SelfLocationInSibl = SelfLocationInSibl;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
@@ -116,8 +116,14 @@ pub fn genesis_config() -> ChainSpec {
 			aura: Default::default(),
 			aura_ext: Default::default(),
 			polkadot_xcm: PolkadotXcmConfig { safe_xcm_version: Some(SAFE_XCM_VERSION) },
+			remote_governance: RemoteGovernanceConfig {
+				guardians: vec![InitialGuardian::get()],
+				threshold: 1,
+			},
 			sudo: SudoConfig { key: Some(root) },
 			parachain_system: Default::default(),
+			xcm_allowlist: XcmAllowlistConfig { allowlist: default_xcm_allowlist() },
+			xcm_trust: XcmTrustConfig { trusted: default_xcm_trust() },
 		}
 	}
 
@@ -178,8 +184,14 @@ pub fn development_config() -> ChainSpec {
 			aura: Default::default(),
 			aura_ext: Default::default(),
 			polkadot_xcm: PolkadotXcmConfig { safe_xcm_version: Some(SAFE_XCM_VERSION) },
+			remote_governance: RemoteGovernanceConfig {
+				guardians: vec![root.clone()],
+				threshold: 1,
+			},
 			sudo: SudoConfig { key: Some(root) },
 			parachain_system: Default::default(),
+			xcm_allowlist: XcmAllowlistConfig { allowlist: default_xcm_allowlist() },
+			xcm_trust: XcmTrustConfig { trusted: default_xcm_trust() },
 		}
 	}
 